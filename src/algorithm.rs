@@ -1,4 +1,7 @@
+use crate::primitives::{MaybeSend, MaybeSync};
 use crate::problem::OptProblemKind;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 pub enum TheoreticGuarantee {
     Consistent,
@@ -29,3 +32,19 @@ where
         self.validate(instance, solution).is_correct()
     }
 }
+
+/// Runs `algorithm` on every instance and returns the solutions in the order of the
+/// instances. With the `parallel` feature the instances are solved on the rayon
+/// thread pool.
+pub fn run_batch<V, A>(algorithm: &A, instances: &[V]) -> Vec<V::Solution>
+where
+    V: OptProblemKind + MaybeSync,
+    V::Solution: MaybeSend,
+    A: Algorithm<V> + MaybeSync,
+{
+    #[cfg(feature = "parallel")]
+    let instances = instances.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let instances = instances.iter();
+    instances.map(|instance| algorithm.run(instance)).collect()
+}
@@ -1,9 +1,13 @@
 use crate::algorithm::*;
-use crate::knapsack::{DefaultItem, Instance, Item, Solution};
-use crate::primitives::Numeric;
+use crate::knapsack::{Instance, Item, Solution};
+use crate::primitives::{MaybeSend, MaybeSync, Numeric};
 use crate::problem::{OptProblemKind, SolutionKind};
-use crate::program::LpSolver;
+use crate::program::branch_and_bound;
 use crate::reduction::Reduction;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
 
 macro_rules! max {
     ($x: expr) => ($x);
@@ -17,6 +21,22 @@ macro_rules! max {
     }}
 }
 
+/// Item indices by decreasing ratio of cost and weight.
+fn by_ratio<I, C, W>(instance: &Instance<I, C, W>) -> Vec<usize>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    let ratio = |index: usize| {
+        let item = &instance.items()[index];
+        (*item.cost()).into() / (*item.weight()).into()
+    };
+    let mut order: Vec<usize> = (0..instance.number_of_items()).collect();
+    order.sort_by(|&a, &b| ratio(b).partial_cmp(&ratio(a)).unwrap());
+    order
+}
+
 pub struct Greedy;
 
 impl<I, C, W> Algorithm<Instance<I, C, W>> for Greedy
@@ -26,12 +46,9 @@ where
     W: Numeric,
 {
     fn run(&self, instance: &Instance<I, C, W>) -> Solution {
-        let mut indexed_items: Vec<(usize, &I)> = instance.items().iter().enumerate().collect();
-        indexed_items.sort_by(|(_, b), (_, a)| {
-            ((*a.cost()).into() / (*a.weight()).into())
-                .partial_cmp(&((*b.cost()).into() / (*b.weight()).into()))
-                .unwrap()
-        });
+        let indexed_items = by_ratio(instance)
+            .into_iter()
+            .map(|index| (index, &instance.items()[index]));
         let mut weight: W = W::zero();
         let mut next: usize = 0;
         let mut packed: Vec<usize> = Vec::new();
@@ -65,7 +82,8 @@ where
     W: Numeric,
 {
     fn validate(&self, instance: &Instance<I, C, W>, solution: &Solution) -> TheoreticGuarantee {
-        let optimal_solution = instance.solve_by_reduction(&LpSolver::CBC);
+        let optimal_solution =
+            instance.solve_by_reduction(&branch_and_bound::BranchAndBound::default());
         match (solution.cost(instance), optimal_solution.cost(instance)) {
             (Some(alg), Some(opt)) => {
                 if alg.into() < 0.5 * opt.into() {
@@ -81,12 +99,39 @@ where
     }
 }
 
+/// Row of the capacity-indexed dynamic program after considering an item of the given
+/// weight and cost, computed from the row before it. With the `parallel` feature the
+/// capacities are distributed over the rayon thread pool.
+fn dp_row<C: Numeric + MaybeSend + MaybeSync>(
+    previous: &[C],
+    next: &mut [C],
+    weight: usize,
+    cost: C,
+) {
+    let value = |j: usize| {
+        if weight > j {
+            previous[j]
+        } else {
+            max!(previous[j], previous[j - weight] + cost)
+        }
+    };
+    #[cfg(feature = "parallel")]
+    next.par_iter_mut()
+        .enumerate()
+        .with_min_len(1 << 12)
+        .for_each(|(j, entry)| *entry = value(j));
+    #[cfg(not(feature = "parallel"))]
+    next.iter_mut()
+        .enumerate()
+        .for_each(|(j, entry)| *entry = value(j));
+}
+
 pub struct SimpleDP;
 
 impl<I, C> Algorithm<Instance<I, C, u32>> for SimpleDP
 where
     I: Item<C, u32>,
-    C: Numeric,
+    C: Numeric + MaybeSend + MaybeSync,
 {
     fn run(&self, instance: &Instance<I, C, u32>) -> Solution {
         let mut values = vec![
@@ -95,16 +140,13 @@ where
         ];
 
         for (i, item) in instance.items().iter().enumerate() {
-            for j in 1..*instance.bag_size() + 1 {
-                values[i + 1][j as usize] = if *item.weight() > j {
-                    values[i][j as usize]
-                } else {
-                    max!(
-                        values[i][j as usize],
-                        values[i][(j - item.weight()) as usize] + *item.cost()
-                    )
-                }
-            }
+            let (previous, next) = values.split_at_mut(i + 1);
+            dp_row(
+                &previous[i],
+                &mut next[0],
+                *item.weight() as usize,
+                *item.cost(),
+            );
         }
 
         let mut packed: Vec<usize> = Vec::new();
@@ -123,52 +165,389 @@ where
     }
 }
 
+/// Same dynamic program as [`SimpleDP`] that only keeps single rows of the table. The
+/// items are split in halves recursively. While the rows of the second half are
+/// computed, every capacity carries the capacity at the middle that the backtracking
+/// of [`SimpleDP`] reaches from it, which splits the remaining capacity between the
+/// halves. The first half is recovered before the second one, so that the row before
+/// every half is at hand by then. Only a constant number of rows of length
+/// `bag_size + 1` are alive at the same time and the running time grows to
+/// `O(n * bag_size * log n)`. Ties are broken as in [`SimpleDP`], so both return the
+/// same packing.
+pub struct HirschbergDP;
+
+impl HirschbergDP {
+    /// Recovers the packed items among `lo..hi` from `row`, the table row before item
+    /// `lo`, where `capacity` is the weight left for them by the items after `hi`.
+    /// Afterwards `row` is the table row after item `hi - 1`.
+    fn reconstruct<I, C>(
+        items: &[I],
+        lo: usize,
+        hi: usize,
+        row: &mut Vec<C>,
+        capacity: usize,
+        packed: &mut Vec<usize>,
+    ) where
+        I: Item<C, u32>,
+        C: Numeric + MaybeSend + MaybeSync,
+    {
+        if hi - lo == 1 {
+            let mut next = row.clone();
+            dp_row(
+                row,
+                &mut next,
+                *items[lo].weight() as usize,
+                *items[lo].cost(),
+            );
+            if next[capacity] != row[capacity] {
+                packed.push(lo);
+            }
+            *row = next;
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        let middle = {
+            let mut current = row[..=capacity].to_vec();
+            let mut buffer = current.clone();
+            for item in &items[lo..mid] {
+                dp_row(&current, &mut buffer, *item.weight() as usize, *item.cost());
+                std::mem::swap(&mut current, &mut buffer);
+            }
+            let mut reached: Vec<usize> = (0..=capacity).collect();
+            let mut reached_buffer = reached.clone();
+            for item in &items[mid..hi] {
+                let weight = *item.weight() as usize;
+                dp_row(&current, &mut buffer, weight, *item.cost());
+                for (j, entry) in reached_buffer.iter_mut().enumerate() {
+                    *entry = if buffer[j] != current[j] {
+                        reached[j - weight]
+                    } else {
+                        reached[j]
+                    };
+                }
+                std::mem::swap(&mut current, &mut buffer);
+                std::mem::swap(&mut reached, &mut reached_buffer);
+            }
+            reached[capacity]
+        };
+        HirschbergDP::reconstruct(items, lo, mid, row, middle, packed);
+        HirschbergDP::reconstruct(items, mid, hi, row, capacity, packed);
+    }
+}
+
+impl<I, C> Algorithm<Instance<I, C, u32>> for HirschbergDP
+where
+    I: Item<C, u32>,
+    C: Numeric + MaybeSend + MaybeSync,
+{
+    fn run(&self, instance: &Instance<I, C, u32>) -> Solution {
+        let mut packed: Vec<usize> = Vec::new();
+        if instance.number_of_items() > 0 {
+            let capacity = *instance.bag_size() as usize;
+            let mut row = vec![C::zero(); capacity + 1];
+            HirschbergDP::reconstruct(
+                instance.items(),
+                0,
+                instance.number_of_items(),
+                &mut row,
+                capacity,
+                &mut packed,
+            );
+        }
+        packed.sort_unstable();
+        Solution::Solved {
+            packed_items: packed,
+        }
+    }
+}
+
+/// Largest number of bytes the table of the profit-indexed dynamic program may take.
+const PROFIT_TABLE_LIMIT: usize = 1 << 31;
+
+/// Table of the dynamic program over the profit: for every total profit up to a cap
+/// it keeps the minimum weight of a packing achieving it, i.e. it takes `O(n * P)` time
+/// for the cap `P`.
+struct ProfitTable<W> {
+    lightest: Vec<Option<W>>,
+    /// Bit set per item of the profits whose lightest packing got improved by the item.
+    taken: Vec<Vec<u64>>,
+}
+
+impl<W: Numeric> ProfitTable<W> {
+    /// Fails if the table would exceed [`PROFIT_TABLE_LIMIT`].
+    fn new(profits: &[usize], weights: &[W], size: W, cap: usize) -> Result<Self, String> {
+        let total = profits
+            .iter()
+            .fold(0usize, |total, &p| total.saturating_add(p));
+        let length = total.min(cap).saturating_add(1);
+        let rows = profits
+            .iter()
+            .zip(weights.iter())
+            .filter(|&(&profit, &weight)| profit != 0 && profit <= cap && weight <= size)
+            .count();
+        let bytes = length
+            .saturating_mul(std::mem::size_of::<Option<W>>())
+            .saturating_add(rows.saturating_mul(length / 64 + 1).saturating_mul(8));
+        if bytes > PROFIT_TABLE_LIMIT {
+            return Err(format!(
+                "Profit table with {} entries exceeds the limit of {} bytes",
+                length, PROFIT_TABLE_LIMIT
+            ));
+        }
+        let mut lightest: Vec<Option<W>> = vec![None; length];
+        lightest[0] = Some(W::zero());
+        let mut taken = vec![Vec::new(); profits.len()];
+        let mut reached = 0;
+        for (i, (&profit, &weight)) in profits.iter().zip(weights.iter()).enumerate() {
+            if profit == 0 || profit > cap || weight > size {
+                continue;
+            }
+            let upper = (reached + profit).min(cap);
+            taken[i] = vec![0; upper / 64 + 1];
+            for p in (profit..=upper).rev() {
+                if let Some(previous) = lightest[p - profit] {
+                    let candidate = previous + weight;
+                    let improves = match lightest[p] {
+                        Some(current) => candidate < current,
+                        None => true,
+                    };
+                    if candidate <= size && improves {
+                        lightest[p] = Some(candidate);
+                        taken[i][p / 64] |= 1 << (p % 64);
+                    }
+                }
+            }
+            reached = upper;
+        }
+        Ok(ProfitTable { lightest, taken })
+    }
+
+    fn is_taken(&self, item: usize, profit: usize) -> bool {
+        match self.taken[item].get(profit / 64) {
+            Some(bits) => bits & (1 << (profit % 64)) != 0,
+            None => false,
+        }
+    }
+
+    /// Indices of the packed items of the lightest packing with the given profit.
+    fn packing(&self, profits: &[usize], mut profit: usize) -> Vec<usize> {
+        let mut packed = Vec::new();
+        for i in (0..profits.len()).rev() {
+            if self.is_taken(i, profit) {
+                packed.push(i);
+                profit -= profits[i];
+            }
+        }
+        packed.sort_unstable();
+        packed
+    }
+}
+
+/// Indices of the packed items of a most profitable packing, where no packing is
+/// more profitable than `cap`.
+fn profit_dp<W: Numeric>(
+    profits: &[usize],
+    weights: &[W],
+    size: W,
+    cap: usize,
+) -> Result<Vec<usize>, String> {
+    let table = ProfitTable::new(profits, weights, size, cap)?;
+    let profit = (0..table.lightest.len())
+        .rev()
+        .find(|&p| table.lightest[p].is_some())
+        .unwrap();
+    Ok(table.packing(profits, profit))
+}
+
+/// Profit-indexed counterpart of [`SimpleDP`] for integral non-negative costs and any
+/// weight type. Items with negative costs are never packed.
+pub struct ProfitDP;
+
+impl<I, C, W> Algorithm<Instance<I, C, W>> for ProfitDP
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn run(&self, instance: &Instance<I, C, W>) -> Solution {
+        let mut profits = Vec::with_capacity(instance.number_of_items());
+        for (index, item) in instance.items().iter().enumerate() {
+            let cost: f64 = (*item.cost()).into();
+            if cost.fract() != 0.0 {
+                return Solution::Failed(format!(
+                    "Cost {} of item {} is not integral",
+                    item.cost(),
+                    index
+                ));
+            }
+            if cost >= usize::MAX as f64 {
+                return Solution::Failed(format!(
+                    "Cost {} of item {} is out of range",
+                    item.cost(),
+                    index
+                ));
+            }
+            profits.push(cost.max(0.0) as usize);
+        }
+        let weights: Vec<W> = instance.items().iter().map(|item| *item.weight()).collect();
+        let size = *instance.bag_size();
+
+        // Dantzig's bound on the optimum caps the table.
+        let mut capacity: f64 = size.into();
+        let mut bound = 0.0;
+        for index in by_ratio(instance) {
+            let (profit, weight) = (profits[index] as f64, weights[index].into());
+            if profit == 0.0 || weights[index] > size {
+                continue;
+            }
+            if weight > capacity {
+                bound += capacity * profit / weight;
+                break;
+            }
+            capacity -= weight;
+            bound += profit;
+        }
+        let cap = (bound + 1e-9 * (1.0 + bound)).floor() as usize;
+        match profit_dp(&profits, &weights, size, cap) {
+            Ok(packed_items) => Solution::Solved { packed_items },
+            Err(message) => Solution::Failed(message),
+        }
+    }
+}
+
+/// Approximation scheme of Lawler and Kellerer and Pferschy. With a lower bound `L`
+/// on the optimum from [`Greedy`], items of cost above `eps * L / 2` are large and
+/// the others small. The costs of the large items are scaled down to multiples of
+/// `eps^2 * L / 4` and the large items are solved by the profit-indexed dynamic
+/// program, for which only the lightest items of every scaled cost that can occur
+/// together in a packing are kept. Every state is completed greedily with the small
+/// items. It achieves at least `1 - eps` of the optimum in
+/// `O(n log n + log(1 / eps) / eps^4)` time, independently of the bag size.
 pub struct FPTAS {
     eps: f64,
 }
 
 impl FPTAS {
-    fn new(eps: f64) -> Self {
+    pub fn new(eps: f64) -> Self {
+        if !(eps > 0.0 && eps <= 1.0) {
+            panic!("Epsilon must lie in (0, 1]!");
+        }
         FPTAS { eps }
     }
 }
 
-impl<I, C> Algorithm<Instance<I, C, u32>> for FPTAS
+impl<I, C, W> Algorithm<Instance<I, C, W>> for FPTAS
 where
-    I: Item<C, u32>,
+    I: Item<C, W>,
     C: Numeric,
+    W: Numeric,
 {
-    fn run(&self, instance: &Instance<I, C, u32>) -> Solution {
-        let highest_cost: C = instance
-            .items()
-            .iter()
-            .map(|item| *item.cost())
-            .max_by(|a, b| a.partial_cmp(&b).unwrap())
-            .unwrap();
+    fn run(&self, instance: &Instance<I, C, W>) -> Solution {
+        let items = instance.items();
+        let size = *instance.bag_size();
+        let cost = |index: usize| -> f64 { (*items[index].cost()).into() };
+        let order: Vec<usize> = by_ratio(instance)
+            .into_iter()
+            .filter(|&index| *items[index].weight() <= size && *items[index].cost() > C::zero())
+            .collect();
+
+        let mut lower_bound = order.iter().map(|&index| cost(index)).fold(0.0, f64::max);
+        let mut greedy = (W::zero(), 0.0);
+        for &index in &order {
+            if greedy.0 + *items[index].weight() <= size {
+                greedy = (greedy.0 + *items[index].weight(), greedy.1 + cost(index));
+            }
+        }
+        lower_bound = lower_bound.max(greedy.1);
+        if order.is_empty() {
+            return Solution::Solved {
+                packed_items: Vec::new(),
+            };
+        }
 
-        let k = self.eps * (highest_cost.into() / instance.number_of_items() as f64);
+        // The optimum is at most twice the lower bound, so no packing of large items
+        // exceeds the scaled cost `cap`.
+        let threshold = self.eps / 2.0 * lower_bound;
+        let scale = self.eps / 2.0 * threshold;
+        let cap = (2.0 * lower_bound / scale).floor() as usize;
+        let (large, small): (Vec<usize>, Vec<usize>) = order
+            .into_iter()
+            .partition(|&index| cost(index) > threshold);
 
-        let updated_items: Vec<DefaultItem<u32>> = instance
-            .items()
-            .iter()
-            .map(|item| DefaultItem {
-                cost: ((*item.cost()).into() / k).floor() as u32,
-                weight: *item.weight(),
-            })
+        let mut by_profit: Vec<(usize, usize)> = large
+            .into_iter()
+            .map(|index| ((cost(index) / scale).floor() as usize, index))
             .collect();
+        by_profit.sort_by(|&(p, a), &(q, b)| {
+            p.cmp(&q)
+                .then(items[a].weight().partial_cmp(items[b].weight()).unwrap())
+        });
+        let mut large = Vec::new();
+        let mut profits = Vec::new();
+        let mut lighter = 0;
+        for (position, &(profit, index)) in by_profit.iter().enumerate() {
+            if position > 0 && by_profit[position - 1].0 != profit {
+                lighter = 0;
+            }
+            if lighter < cap / profit {
+                large.push(index);
+                profits.push(profit);
+            }
+            lighter += 1;
+        }
+        let weights: Vec<W> = large.iter().map(|&index| *items[index].weight()).collect();
+        let table = match ProfitTable::new(&profits, &weights, size, cap) {
+            Ok(table) => table,
+            Err(message) => return Solution::Failed(message),
+        };
+
+        let mut prefix_weights = vec![W::zero()];
+        let mut prefix_costs = vec![0.0];
+        for &index in &small {
+            prefix_weights.push(*prefix_weights.last().unwrap() + *items[index].weight());
+            prefix_costs.push(prefix_costs.last().unwrap() + cost(index));
+        }
+        let (mut best, mut best_value) = (0, 0.0);
+        for (profit, lightest) in table.lightest.iter().enumerate() {
+            if let Some(weight) = *lightest {
+                let fitting = prefix_weights.partition_point(|&w| weight + w <= size);
+                let value = profit as f64 * scale + prefix_costs[fitting - 1];
+                if value > best_value {
+                    best = profit;
+                    best_value = value;
+                }
+            }
+        }
 
-        let updated_instance = Instance::new(updated_items, *instance.bag_size());
-        updated_instance.run(SimpleDP)
+        let mut packed: Vec<usize> = table
+            .packing(&profits, best)
+            .into_iter()
+            .map(|position| large[position])
+            .collect();
+        let mut weight = packed
+            .iter()
+            .fold(W::zero(), |weight, &index| weight + *items[index].weight());
+        for &index in &small {
+            if weight + *items[index].weight() <= size {
+                packed.push(index);
+                weight += *items[index].weight();
+            }
+        }
+        packed.sort_unstable();
+        Solution::Solved {
+            packed_items: packed,
+        }
     }
 }
 
-impl<I, C> TheoreticValidation<Instance<I, C, u32>> for FPTAS
+impl<I, C, W> TheoreticValidation<Instance<I, C, W>> for FPTAS
 where
-    I: Item<C, u32>,
+    I: Item<C, W>,
     C: Numeric,
+    W: Numeric,
 {
-    fn validate(&self, instance: &Instance<I, C, u32>, solution: &Solution) -> TheoreticGuarantee {
-        let optimal_solution = instance.run(SimpleDP);
+    fn validate(&self, instance: &Instance<I, C, W>, solution: &Solution) -> TheoreticGuarantee {
+        let optimal_solution = instance.run(BranchAndBound::default());
         match (solution.cost(instance), optimal_solution.cost(instance)) {
             (Some(alg), Some(opt)) => {
                 if alg.into() < (1.0 - self.eps) * opt.into() {
@@ -186,75 +565,1519 @@ where
     }
 }
 
-#[cfg(test)]
-mod test_super {
-    use super::*;
-    use rand::distributions::Uniform;
-    use rand::{thread_rng, Rng};
+/// Approximation scheme of Sahni: every fitting subset of at most `k` items is
+/// completed greedily by decreasing ratio with items that are not more valuable than
+/// any item of the subset, and the best packing is returned. It achieves at least
+/// `1 - 1 / (k + 1)` of the optimum in `O(n^(k + 1))` time.
+pub struct PTAS {
+    k: usize,
+}
 
-    #[test]
-    fn solving_by_greedy_works() {
-        let instance = Instance::from((vec![(1, 2), (2, 3), (2, 1)], 5));
-        let solution = instance.run(Greedy);
-        assert!(solution.is_solved());
-        let items = solution.as_solution().unwrap();
-        assert_eq!(items, vec![1, 2])
+impl PTAS {
+    pub fn new(k: usize) -> Self {
+        PTAS { k }
     }
 
-    #[test]
-    fn random_validation_greedy_alg() {
-        let mut rng = thread_rng();
-        let costs: Vec<u32> = rng.sample_iter(Uniform::new(0, 100)).take(30).collect();
-        let weights: Vec<u32> = rng.sample_iter(Uniform::new(1, 100)).take(30).collect();
-        let size = rng.sample(Uniform::new(400, 700));
-        let instance = Instance::from((costs, weights, size));
-        let solution = instance.run(Greedy);
-        assert!(solution.is_solved());
-        assert!(Greedy.is_correct(&instance, &solution));
+    fn enumerate<I, C, W>(
+        &self,
+        instance: &Instance<I, C, W>,
+        order: &[usize],
+        start: usize,
+        subset: &mut Vec<usize>,
+        weight: W,
+        best: &mut Option<(C, Vec<usize>)>,
+    ) where
+        I: Item<C, W>,
+        C: Numeric,
+        W: Numeric,
+    {
+        let items = instance.items();
+        let mut packed = subset.clone();
+        let mut packed_weight = weight;
+        let limit = subset.iter().map(|&index| *items[index].cost()).fold(
+            None,
+            |limit: Option<C>, cost| match limit {
+                Some(limit) if limit < cost => Some(limit),
+                _ => Some(cost),
+            },
+        );
+        for &index in order {
+            let item = &items[index];
+            let allowed = match limit {
+                Some(limit) => *item.cost() <= limit,
+                None => true,
+            };
+            if allowed
+                && !subset.contains(&index)
+                && packed_weight + *item.weight() <= *instance.bag_size()
+            {
+                packed.push(index);
+                packed_weight += *item.weight();
+            }
+        }
+        let cost: C = packed.iter().map(|&index| *items[index].cost()).sum();
+        if !matches!(best, Some((best_cost, _)) if *best_cost >= cost) {
+            *best = Some((cost, packed));
+        }
+
+        if subset.len() == self.k {
+            return;
+        }
+        for (position, &index) in order.iter().enumerate().skip(start) {
+            if weight + *items[index].weight() <= *instance.bag_size() {
+                subset.push(index);
+                self.enumerate(
+                    instance,
+                    order,
+                    position + 1,
+                    subset,
+                    weight + *items[index].weight(),
+                    best,
+                );
+                subset.pop();
+            }
+        }
     }
+}
 
-    #[test]
-    fn solving_by_simple_dp_works() {
-        let instance = Instance::from((vec![(1, 2), (2, 3), (2, 1)], 5));
-        let solution = instance.run(SimpleDP);
-        assert!(solution.is_solved());
-        let items = solution.as_solution().unwrap();
-        assert_eq!(items, vec![1, 2])
+impl<I, C, W> Algorithm<Instance<I, C, W>> for PTAS
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn run(&self, instance: &Instance<I, C, W>) -> Solution {
+        let order: Vec<usize> = by_ratio(instance)
+            .into_iter()
+            .filter(|&index| *instance.items()[index].cost() > C::zero())
+            .collect();
+        let mut best = None;
+        self.enumerate(instance, &order, 0, &mut Vec::new(), W::zero(), &mut best);
+        let (_, mut packed) = best.unwrap();
+        packed.sort_unstable();
+        Solution::Solved {
+            packed_items: packed,
+        }
     }
+}
 
-    #[test]
-    fn random_validation_simple_dp_alg() {
-        let mut rng = thread_rng();
-        let costs: Vec<u32> = rng.sample_iter(Uniform::new(0, 100)).take(30).collect();
-        let weights: Vec<u32> = rng.sample_iter(Uniform::new(1, 100)).take(30).collect();
-        let size: u32 = rng.sample(Uniform::new(400, 700));
-        let instance = Instance::from((costs, weights, size));
-        let dp_solution = instance.run(SimpleDP);
-        let ilp_solution = instance.solve_by_reduction(&LpSolver::CBC);
-        assert!(dp_solution.is_solved());
-        assert!(ilp_solution.is_solved());
-        assert_eq!(ilp_solution.cost(&instance), dp_solution.cost(&instance));
+impl<I, C, W> TheoreticValidation<Instance<I, C, W>> for PTAS
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn validate(&self, instance: &Instance<I, C, W>, solution: &Solution) -> TheoreticGuarantee {
+        let optimal_solution = instance.run(BranchAndBound::default());
+        let ratio = 1.0 - 1.0 / (self.k as f64 + 1.0);
+        match (solution.cost(instance), optimal_solution.cost(instance)) {
+            (Some(alg), Some(opt)) => {
+                if alg.into() < ratio * opt.into() - 1e-9 * (1.0 + opt.into().abs()) {
+                    TheoreticGuarantee::Inconsistent(format!(
+                        "PTAS (k = {}) did not achieve its theoretical approximation ratio: {} < {} * {}",
+                        self.k, alg, ratio, opt
+                    ))
+                } else {
+                    TheoreticGuarantee::Consistent
+                }
+            }
+            _ => TheoreticGuarantee::Failed(String::from(
+                "Error: Cost of optimal solution and algorithm could not have been computed!",
+            )),
+        }
     }
+}
 
-    #[test]
-    fn solving_by_fptas_works() {
-        let instance = Instance::from((vec![(1, 2), (2, 3), (2, 1)], 5));
-        let solution = instance.run(FPTAS::new(0.5));
-        assert!(solution.is_solved());
-        let items = solution.as_solution().unwrap();
-        assert_eq!(items, vec![1, 2])
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UpperBound {
+    /// Value of the LP relaxation, i.e. the greedy packing plus a fraction of the
+    /// break item.
+    Dantzig,
+    /// Martello and Toth's U2, which either excludes the break item and fills the
+    /// residual capacity at the ratio of the next item, or includes it and frees the
+    /// excess at the ratio of the previous one.
+    MartelloToth,
+}
+
+/// Exact depth-first branch and bound over the items sorted by decreasing ratio of
+/// cost and weight. Unlike [`SimpleDP`] it only needs additions of costs and weights,
+/// so it works for every [`Numeric`] type.
+pub struct BranchAndBound {
+    upper_bound: UpperBound,
+}
+
+impl BranchAndBound {
+    pub fn new(upper_bound: UpperBound) -> Self {
+        BranchAndBound { upper_bound }
     }
+}
 
-    #[test]
-    fn random_validation_fptas_alg() {
-        let mut rng = thread_rng();
-        let costs: Vec<u32> = rng.sample_iter(Uniform::new(0, 100)).take(30).collect();
-        let weights: Vec<u32> = rng.sample_iter(Uniform::new(1, 100)).take(30).collect();
-        let size: u32 = rng.sample(Uniform::new(400, 700));
-        let eps: f64 = rng.sample(Uniform::new(0.05, 0.95));
-        let instance = Instance::from((costs, weights, size));
-        let solution = instance.run(FPTAS::new(eps));
-        assert!(solution.is_solved());
-        assert!(FPTAS::new(eps).is_correct(&instance, &solution));
+impl Default for BranchAndBound {
+    fn default() -> Self {
+        BranchAndBound::new(UpperBound::MartelloToth)
+    }
+}
+
+struct Search<'a, I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    instance: &'a Instance<I, C, W>,
+    upper_bound: UpperBound,
+    /// Indices of the items with positive cost by decreasing ratio.
+    order: Vec<usize>,
+    packed: Vec<usize>,
+    best: Vec<usize>,
+    best_value: f64,
+}
+
+impl<'a, I, C, W> Search<'a, I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn cost(&self, position: usize) -> f64 {
+        (*self.instance.items()[self.order[position]].cost()).into()
+    }
+
+    fn weight(&self, position: usize) -> f64 {
+        (*self.instance.items()[self.order[position]].weight()).into()
+    }
+
+    fn ratio(&self, position: usize) -> f64 {
+        self.cost(position) / self.weight(position)
+    }
+
+    /// Bound on the value of the items from `depth` on for the given residual capacity.
+    fn bound(&self, depth: usize, mut capacity: f64) -> f64 {
+        let mut value = 0.0;
+        let mut position = depth;
+        while position < self.order.len() && self.weight(position) <= capacity {
+            capacity -= self.weight(position);
+            value += self.cost(position);
+            position += 1;
+        }
+        if position == self.order.len() {
+            return value;
+        }
+        match self.upper_bound {
+            UpperBound::Dantzig => value + capacity * self.ratio(position),
+            UpperBound::MartelloToth => {
+                let without = if position + 1 < self.order.len() {
+                    value + capacity * self.ratio(position + 1)
+                } else {
+                    value
+                };
+                let with = if position > depth {
+                    value + self.cost(position)
+                        - (self.weight(position) - capacity) * self.ratio(position - 1)
+                } else {
+                    f64::NEG_INFINITY
+                };
+                without.max(with)
+            }
+        }
+    }
+
+    fn explore(&mut self, depth: usize, weight: W, value: f64) {
+        if value > self.best_value {
+            self.best_value = value;
+            self.best = self.packed.clone();
+        }
+        if depth == self.order.len() {
+            return;
+        }
+        let capacity = (*self.instance.bag_size()).into() - weight.into();
+        if value + self.bound(depth, capacity) <= self.best_value {
+            return;
+        }
+
+        let index = self.order[depth];
+        let item_weight = *self.instance.items()[index].weight();
+        if weight + item_weight <= *self.instance.bag_size() {
+            self.packed.push(index);
+            self.explore(depth + 1, weight + item_weight, value + self.cost(depth));
+            self.packed.pop();
+        }
+        self.explore(depth + 1, weight, value);
+    }
+}
+
+impl<I, C, W> Algorithm<Instance<I, C, W>> for BranchAndBound
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn run(&self, instance: &Instance<I, C, W>) -> Solution {
+        let order: Vec<usize> = by_ratio(instance)
+            .into_iter()
+            .filter(|&index| *instance.items()[index].cost() > C::zero())
+            .collect();
+
+        let mut search = Search {
+            instance,
+            upper_bound: self.upper_bound,
+            order,
+            packed: Vec::new(),
+            best: Vec::new(),
+            best_value: 0.0,
+        };
+        search.explore(0, W::zero(), 0.0);
+        let mut packed = search.best;
+        packed.sort_unstable();
+        Solution::Solved {
+            packed_items: packed,
+        }
+    }
+}
+
+impl<I, C, W> TheoreticValidation<Instance<I, C, W>> for BranchAndBound
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn validate(&self, instance: &Instance<I, C, W>, solution: &Solution) -> TheoreticGuarantee {
+        let optimal_solution =
+            instance.solve_by_reduction(&branch_and_bound::BranchAndBound::default());
+        match (solution.cost(instance), optimal_solution.cost(instance)) {
+            (Some(alg), Some(opt)) => {
+                if (alg.into() - opt.into()).abs() > 1e-6 * (1.0 + opt.into().abs()) {
+                    TheoreticGuarantee::Inconsistent(format!(
+                        "Branch and bound did not find an optimal solution: {} != {}",
+                        alg, opt
+                    ))
+                } else {
+                    TheoreticGuarantee::Consistent
+                }
+            }
+            _ => TheoreticGuarantee::Failed(String::from(
+                "Error: Cost of optimal solution and algorithm could not have been computed!",
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CoreStrategy {
+    /// Depth-first search in the style of expknap: while the packing fits, the next
+    /// item right of the core is added or skipped, otherwise the next item left of
+    /// the core is removed or kept.
+    ExpKnap,
+    /// Dynamic programming in the style of minknap: the core grows by one item on
+    /// each side per round, and the undominated states of the core that can still
+    /// beat the best packing are enumerated.
+    MinKnap,
+}
+
+/// Figures reported by [`Core::solve`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CoreStatistics {
+    break_item: usize,
+    core_size: usize,
+    states: usize,
+}
+
+impl CoreStatistics {
+    /// Position of the break item among the items sorted by ratio.
+    pub fn break_item(&self) -> usize {
+        self.break_item
+    }
+
+    /// Number of items around the break item whose decision was enumerated.
+    pub fn core_size(&self) -> usize {
+        self.core_size
+    }
+
+    /// Search nodes of expknap or dynamic programming states of minknap.
+    pub fn states(&self) -> usize {
+        self.states
+    }
+}
+
+/// Exact core algorithms after Pisinger. The items are sorted by ratio as in
+/// [`Greedy`], and only the decisions for items around the break item, where
+/// optimal packings usually differ from the greedy one, are enumerated. Bounds on
+/// the items outside of the core decide when the core is large enough.
+pub struct Core {
+    strategy: CoreStrategy,
+}
+
+impl Core {
+    pub fn new(strategy: CoreStrategy) -> Self {
+        Core { strategy }
+    }
+
+    pub fn solve<I, C, W>(&self, instance: &Instance<I, C, W>) -> (Solution, CoreStatistics)
+    where
+        I: Item<C, W>,
+        C: Numeric,
+        W: Numeric,
+    {
+        let sorted = SortedItems::new(instance);
+        let mut statistics = CoreStatistics {
+            break_item: sorted.break_item,
+            ..CoreStatistics::default()
+        };
+        let positions = if sorted.break_item == sorted.order.len() {
+            statistics.states = 1;
+            (0..sorted.order.len()).collect()
+        } else {
+            match self.strategy {
+                CoreStrategy::ExpKnap => sorted.expknap(&mut statistics),
+                CoreStrategy::MinKnap => sorted.minknap(&mut statistics),
+            }
+        };
+        let mut packed: Vec<usize> = positions
+            .into_iter()
+            .map(|position| sorted.order[position])
+            .collect();
+        packed.sort_unstable();
+        (
+            Solution::Solved {
+                packed_items: packed,
+            },
+            statistics,
+        )
+    }
+}
+
+impl Default for Core {
+    fn default() -> Self {
+        Core::new(CoreStrategy::MinKnap)
+    }
+}
+
+impl<I, C, W> Algorithm<Instance<I, C, W>> for Core
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn run(&self, instance: &Instance<I, C, W>) -> Solution {
+        self.solve(instance).0
+    }
+}
+
+/// Items of positive cost by decreasing ratio. Packings are described relative to
+/// the core `[s, t)`: all items before `s` are packed, all items from `t` on are not,
+/// and `weight` and `cost` only sum up the packed items of the core.
+struct SortedItems<'a, I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    instance: &'a Instance<I, C, W>,
+    order: Vec<usize>,
+    prefix_weights: Vec<W>,
+    prefix_costs: Vec<C>,
+    break_item: usize,
+    /// Whether all costs are integers, so that bounds may be rounded down.
+    integral: bool,
+}
+
+struct Partial<C, W> {
+    weight: W,
+    cost: C,
+    history: usize,
+}
+
+impl<'a, I, C, W> SortedItems<'a, I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn new(instance: &'a Instance<I, C, W>) -> Self {
+        let order: Vec<usize> = by_ratio(instance)
+            .into_iter()
+            .filter(|&index| *instance.items()[index].cost() > C::zero())
+            .collect();
+        let mut prefix_weights = vec![W::zero()];
+        let mut prefix_costs = vec![C::zero()];
+        for &index in &order {
+            let item = &instance.items()[index];
+            prefix_weights.push(*prefix_weights.last().unwrap() + *item.weight());
+            prefix_costs.push(*prefix_costs.last().unwrap() + *item.cost());
+        }
+        let break_item = (0..order.len())
+            .find(|&position| prefix_weights[position + 1] > *instance.bag_size())
+            .unwrap_or(order.len());
+        let integral = order
+            .iter()
+            .all(|&index| (*instance.items()[index].cost()).into().fract() == 0.0);
+        SortedItems {
+            instance,
+            order,
+            prefix_weights,
+            prefix_costs,
+            break_item,
+            integral,
+        }
+    }
+
+    fn weight(&self, position: usize) -> W {
+        *self.instance.items()[self.order[position]].weight()
+    }
+
+    fn cost(&self, position: usize) -> C {
+        *self.instance.items()[self.order[position]].cost()
+    }
+
+    fn ratio(&self, position: usize) -> f64 {
+        self.cost(position).into() / self.weight(position).into()
+    }
+
+    fn fits(&self, s: usize, weight: W) -> bool {
+        self.prefix_weights[s] + weight <= *self.instance.bag_size()
+    }
+
+    fn value(&self, s: usize, cost: C) -> f64 {
+        (self.prefix_costs[s] + cost).into()
+    }
+
+    /// Whether a packing of core `[s, t)` cannot improve on `best`, where improvements
+    /// of integral costs are worth at least one and others have to exceed the rounding
+    /// tolerance. Outside of the core free capacity is filled at most at the ratio of
+    /// item `t` and excess weight is removed at least at the ratio of item `s - 1`.
+    fn is_hopeless(&self, s: usize, t: usize, weight: W, cost: C, best: f64) -> bool {
+        let total: f64 = (self.prefix_weights[s] + weight).into();
+        let size: f64 = (*self.instance.bag_size()).into();
+        let value = self.value(s, cost);
+        let bound = if total <= size {
+            if t < self.order.len() {
+                value + (size - total) * self.ratio(t)
+            } else {
+                value
+            }
+        } else if s > 0 {
+            value - (total - size) * self.ratio(s - 1)
+        } else {
+            return true;
+        };
+        let tolerance = 1e-9 * (1.0 + best.abs());
+        if self.integral {
+            bound + tolerance < best + 1.0
+        } else {
+            bound <= best + tolerance
+        }
+    }
+
+    fn expknap(&self, statistics: &mut CoreStatistics) -> Vec<usize> {
+        let mut search = ExpKnap {
+            items: self,
+            chosen: Vec::new(),
+            best: Vec::new(),
+            best_s: self.break_item,
+            best_value: self.value(self.break_item, C::zero()),
+            core: (self.break_item, self.break_item),
+            nodes: 0,
+        };
+        search.branch(self.break_item, self.break_item, W::zero(), C::zero());
+        statistics.core_size = search.core.1 - search.core.0;
+        statistics.states = search.nodes;
+        (0..search.best_s).chain(search.best).collect()
+    }
+
+    fn minknap(&self, statistics: &mut CoreStatistics) -> Vec<usize> {
+        // (parent, position) of every state that packs the item at `position`
+        let mut history: Vec<(usize, usize)> = vec![(usize::MAX, usize::MAX)];
+        let mut states = vec![Partial {
+            weight: W::zero(),
+            cost: C::zero(),
+            history: 0,
+        }];
+        let (mut s, mut t) = (self.break_item, self.break_item);
+        let (mut best_value, mut best_history, mut best_s) =
+            (self.value(s, C::zero()), 0, self.break_item);
+        statistics.states = 1;
+
+        while !states.is_empty() && (s > 0 || t < self.order.len()) {
+            for side in 0..2 {
+                let position = if side == 0 && t < self.order.len() {
+                    t += 1;
+                    t - 1
+                } else if side == 1 && s > 0 {
+                    s -= 1;
+                    s
+                } else {
+                    continue;
+                };
+                states = self.add_item(states, position, &mut history);
+                statistics.states += states.len();
+                for state in &states {
+                    if self.fits(s, state.weight) && self.value(s, state.cost) > best_value {
+                        best_value = self.value(s, state.cost);
+                        best_history = state.history;
+                        best_s = s;
+                    }
+                }
+                states
+                    .retain(|state| !self.is_hopeless(s, t, state.weight, state.cost, best_value));
+            }
+        }
+        statistics.core_size = t - s;
+
+        let mut positions: Vec<usize> = (0..best_s).collect();
+        let mut node = best_history;
+        while node != 0 {
+            positions.push(history[node].1);
+            node = history[node].0;
+        }
+        positions
+    }
+
+    /// Merges the states without and with the item into the undominated states,
+    /// sorted by increasing weight and cost.
+    fn add_item(
+        &self,
+        states: Vec<Partial<C, W>>,
+        position: usize,
+        history: &mut Vec<(usize, usize)>,
+    ) -> Vec<Partial<C, W>> {
+        let (weight, cost) = (self.weight(position), self.cost(position));
+        let mut merged: Vec<Partial<C, W>> = Vec::with_capacity(2 * states.len());
+        let mut push = |candidate: Partial<C, W>, packs: bool| {
+            if matches!(merged.last(), Some(last) if last.cost >= candidate.cost) {
+                return;
+            }
+            while matches!(merged.last(), Some(last) if last.weight >= candidate.weight) {
+                merged.pop();
+            }
+            let history = if packs {
+                history.push((candidate.history, position));
+                history.len() - 1
+            } else {
+                candidate.history
+            };
+            merged.push(Partial {
+                history,
+                ..candidate
+            });
+        };
+        let (mut without, mut with) = (0, 0);
+        while without < states.len() || with < states.len() {
+            let take_without = with == states.len()
+                || (without < states.len()
+                    && (states[without].weight < states[with].weight + weight
+                        || (states[without].weight == states[with].weight + weight
+                            && states[without].cost >= states[with].cost + cost)));
+            if take_without {
+                let state = &states[without];
+                push(
+                    Partial {
+                        weight: state.weight,
+                        cost: state.cost,
+                        history: state.history,
+                    },
+                    false,
+                );
+                without += 1;
+            } else {
+                let state = &states[with];
+                push(
+                    Partial {
+                        weight: state.weight + weight,
+                        cost: state.cost + cost,
+                        history: state.history,
+                    },
+                    true,
+                );
+                with += 1;
+            }
+        }
+        merged
+    }
+}
+
+struct ExpKnap<'b, 'a, I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    items: &'b SortedItems<'a, I, C, W>,
+    /// Positions inside the core that are currently packed.
+    chosen: Vec<usize>,
+    best: Vec<usize>,
+    best_s: usize,
+    best_value: f64,
+    core: (usize, usize),
+    nodes: usize,
+}
+
+impl<'b, 'a, I, C, W> ExpKnap<'b, 'a, I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn branch(&mut self, s: usize, t: usize, weight: W, cost: C) {
+        self.nodes += 1;
+        self.core = (self.core.0.min(s), self.core.1.max(t));
+        let items = self.items;
+        let fits = items.fits(s, weight);
+        if fits && items.value(s, cost) > self.best_value {
+            self.best_value = items.value(s, cost);
+            self.best = self.chosen.clone();
+            self.best_s = s;
+        }
+        if items.is_hopeless(s, t, weight, cost, self.best_value) {
+            return;
+        }
+
+        if fits {
+            if t == items.order.len() {
+                return;
+            }
+            self.chosen.push(t);
+            self.branch(s, t + 1, weight + items.weight(t), cost + items.cost(t));
+            self.chosen.pop();
+            self.branch(s, t + 1, weight, cost);
+        } else {
+            if s == 0 {
+                return;
+            }
+            self.branch(s - 1, t, weight, cost);
+            self.chosen.push(s - 1);
+            self.branch(
+                s - 1,
+                t,
+                weight + items.weight(s - 1),
+                cost + items.cost(s - 1),
+            );
+            self.chosen.pop();
+        }
+    }
+}
+
+/// Exact meet-in-the-middle algorithm of Horowitz and Sahni. The items are split into
+/// two halves whose subsets are enumerated, and every subset of the first half is
+/// combined with the most valuable fitting subset of the second half. It takes
+/// `O(2^(n/2) * n)` time and `O(2^(n/2))` memory independently of the magnitude of
+/// weights and bag size. The variant of Schroeppel and Shamir generates the subsets
+/// of both halves in sorted order from four quarters with priority queues, so it only
+/// needs `O(2^(n/4))` memory.
+pub struct MeetInTheMiddle {
+    schroeppel_shamir: bool,
+}
+
+impl MeetInTheMiddle {
+    pub fn new() -> Self {
+        MeetInTheMiddle {
+            schroeppel_shamir: false,
+        }
+    }
+
+    pub fn with_schroeppel_shamir(mut self, schroeppel_shamir: bool) -> Self {
+        self.schroeppel_shamir = schroeppel_shamir;
+        self
+    }
+}
+
+impl Default for MeetInTheMiddle {
+    fn default() -> Self {
+        MeetInTheMiddle::new()
+    }
+}
+
+/// Subset of items given as bit mask over the item indices.
+#[derive(Clone, Copy)]
+struct Subset<C, W> {
+    weight: W,
+    cost: C,
+    mask: u64,
+}
+
+impl<C, W> Subset<C, W>
+where
+    C: Numeric,
+    W: Numeric,
+{
+    fn join(&self, other: &Self) -> Self {
+        Subset {
+            weight: self.weight + other.weight,
+            cost: self.cost + other.cost,
+            mask: self.mask | other.mask,
+        }
+    }
+}
+
+/// Subsets of `items` that fit into the bag.
+fn fitting_subsets<I, C, W>(instance: &Instance<I, C, W>, items: &[usize]) -> Vec<Subset<C, W>>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    let mut subsets = vec![Subset {
+        weight: W::zero(),
+        cost: C::zero(),
+        mask: 0,
+    }];
+    for &index in items {
+        let item = Subset {
+            weight: *instance.items()[index].weight(),
+            cost: *instance.items()[index].cost(),
+            mask: 1 << index,
+        };
+        for k in 0..subsets.len() {
+            let extended = subsets[k].join(&item);
+            if extended.weight <= *instance.bag_size() {
+                subsets.push(extended);
+            }
+        }
+    }
+    subsets.retain(|subset| subset.weight <= *instance.bag_size());
+    subsets
+}
+
+/// Entry of a priority queue over sums of two sorted subset lists, ordered by weight.
+struct SumEntry<W> {
+    weight: W,
+    first: usize,
+    second: usize,
+}
+
+impl<W: Numeric> PartialEq for SumEntry<W> {
+    fn eq(&self, other: &Self) -> bool {
+        self.weight == other.weight
+    }
+}
+
+impl<W: Numeric> Eq for SumEntry<W> {}
+
+impl<W: Numeric> PartialOrd for SumEntry<W> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<W: Numeric> Ord for SumEntry<W> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.weight.partial_cmp(&other.weight).unwrap()
+    }
+}
+
+impl MeetInTheMiddle {
+    fn horowitz_sahni<I, C, W>(
+        instance: &Instance<I, C, W>,
+        items: &[usize],
+    ) -> Option<Subset<C, W>>
+    where
+        I: Item<C, W>,
+        C: Numeric,
+        W: Numeric,
+    {
+        let (left, right) = items.split_at(items.len() / 2);
+        let mut second = fitting_subsets(instance, right);
+        second.sort_by(|a, b| {
+            a.weight
+                .partial_cmp(&b.weight)
+                .unwrap()
+                .then(b.cost.partial_cmp(&a.cost).unwrap())
+        });
+        let mut frontier: Vec<Subset<C, W>> = Vec::with_capacity(second.len());
+        for subset in second {
+            if !matches!(frontier.last(), Some(last) if last.cost >= subset.cost) {
+                frontier.push(subset);
+            }
+        }
+
+        let mut best: Option<Subset<C, W>> = None;
+        for subset in fitting_subsets(instance, left) {
+            let fitting = frontier
+                .partition_point(|other| subset.weight + other.weight <= *instance.bag_size());
+            if fitting > 0 {
+                let candidate = subset.join(&frontier[fitting - 1]);
+                if !matches!(best, Some(best) if best.cost >= candidate.cost) {
+                    best = Some(candidate);
+                }
+            }
+        }
+        best
+    }
+
+    fn schroeppel_shamir<I, C, W>(
+        instance: &Instance<I, C, W>,
+        items: &[usize],
+    ) -> Option<Subset<C, W>>
+    where
+        I: Item<C, W>,
+        C: Numeric,
+        W: Numeric,
+    {
+        let quarter = |k: usize| &items[k * items.len() / 4..(k + 1) * items.len() / 4];
+        let by_weight =
+            |a: &Subset<C, W>, b: &Subset<C, W>| a.weight.partial_cmp(&b.weight).unwrap();
+        let first = fitting_subsets(instance, quarter(0));
+        let mut second = fitting_subsets(instance, quarter(1));
+        second.sort_by(|a, b| by_weight(b, a));
+        let third = fitting_subsets(instance, quarter(2));
+        let mut fourth = fitting_subsets(instance, quarter(3));
+        fourth.sort_by(by_weight);
+
+        // sums of the first half by decreasing weight
+        let mut left: BinaryHeap<SumEntry<W>> = (0..first.len())
+            .map(|index| SumEntry {
+                weight: first[index].weight + second[0].weight,
+                first: index,
+                second: 0,
+            })
+            .collect();
+        // sums of the second half by increasing weight
+        let mut right: BinaryHeap<Reverse<SumEntry<W>>> = (0..third.len())
+            .map(|index| {
+                Reverse(SumEntry {
+                    weight: third[index].weight + fourth[0].weight,
+                    first: index,
+                    second: 0,
+                })
+            })
+            .collect();
+
+        let mut best: Option<Subset<C, W>> = None;
+        let mut best_right: Option<Subset<C, W>> = None;
+        while let Some(entry) = left.pop() {
+            if entry.second + 1 < second.len() {
+                left.push(SumEntry {
+                    weight: first[entry.first].weight + second[entry.second + 1].weight,
+                    first: entry.first,
+                    second: entry.second + 1,
+                });
+            }
+            if entry.weight > *instance.bag_size() {
+                continue;
+            }
+            // the lighter the left sum, the more right sums fit
+            while let Some(Reverse(next)) = right.peek() {
+                if entry.weight + next.weight > *instance.bag_size() {
+                    break;
+                }
+                let Reverse(next) = right.pop().unwrap();
+                if next.second + 1 < fourth.len() {
+                    right.push(Reverse(SumEntry {
+                        weight: third[next.first].weight + fourth[next.second + 1].weight,
+                        first: next.first,
+                        second: next.second + 1,
+                    }));
+                }
+                let subset = third[next.first].join(&fourth[next.second]);
+                if !matches!(best_right, Some(best) if best.cost >= subset.cost) {
+                    best_right = Some(subset);
+                }
+            }
+            if let Some(other) = best_right {
+                let candidate = first[entry.first].join(&second[entry.second]).join(&other);
+                if !matches!(best, Some(best) if best.cost >= candidate.cost) {
+                    best = Some(candidate);
+                }
+            }
+        }
+        best
+    }
+}
+
+impl<I, C, W> Algorithm<Instance<I, C, W>> for MeetInTheMiddle
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn run(&self, instance: &Instance<I, C, W>) -> Solution {
+        if instance.number_of_items() > 64 {
+            return Solution::Failed(String::from("Meet in the middle supports at most 64 items"));
+        }
+        let items: Vec<usize> = (0..instance.number_of_items()).collect();
+        let best = if self.schroeppel_shamir {
+            MeetInTheMiddle::schroeppel_shamir(instance, &items)
+        } else {
+            MeetInTheMiddle::horowitz_sahni(instance, &items)
+        };
+        match best {
+            Some(subset) => Solution::Solved {
+                packed_items: items
+                    .into_iter()
+                    .filter(|&index| subset.mask & (1 << index) != 0)
+                    .collect(),
+            },
+            None => Solution::Infeasible,
+        }
+    }
+}
+
+/// Packing on the Pareto frontier computed by [`NemhauserUllmann`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParetoPoint<C, W> {
+    weight: W,
+    cost: C,
+    packed_items: Vec<usize>,
+}
+
+impl<C, W> ParetoPoint<C, W>
+where
+    C: Numeric,
+    W: Numeric,
+{
+    pub fn weight(&self) -> W {
+        self.weight
+    }
+
+    pub fn cost(&self) -> C {
+        self.cost
+    }
+
+    pub fn packed_items(&self) -> &[usize] {
+        &self.packed_items
+    }
+}
+
+/// Pareto optimal packings by increasing weight and strictly increasing cost, i.e.
+/// the most valuable packing for every capacity.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParetoFrontier<C, W> {
+    points: Vec<ParetoPoint<C, W>>,
+}
+
+impl<C, W> ParetoFrontier<C, W>
+where
+    C: Numeric,
+    W: Numeric,
+{
+    pub fn points(&self) -> &[ParetoPoint<C, W>] {
+        &self.points
+    }
+
+    /// The most valuable packing of weight at most `capacity`.
+    pub fn best_within(&self, capacity: W) -> Option<&ParetoPoint<C, W>> {
+        let fitting = self
+            .points
+            .partition_point(|point| point.weight <= capacity);
+        fitting.checked_sub(1).map(|last| &self.points[last])
+    }
+
+    pub fn solution(&self, capacity: W) -> Solution {
+        match self.best_within(capacity) {
+            Some(point) => Solution::Solved {
+                packed_items: point.packed_items.clone(),
+            },
+            None => Solution::Infeasible,
+        }
+    }
+}
+
+/// Exact algorithm of Nemhauser and Ullmann. The Pareto optimal (weight, cost)
+/// states are computed item by item by merging the states without and with the
+/// item and dropping the dominated ones. The running time is linear in the sum of
+/// the sizes of the intermediate frontiers, which is small for many random
+/// instances, and no arithmetic besides additions is needed.
+pub struct NemhauserUllmann;
+
+/// Frontier state whose packing is stored as linked list in an arena of
+/// `(item, previous)` nodes.
+#[derive(Clone, Copy)]
+struct State<C, W> {
+    weight: W,
+    cost: C,
+    node: Option<usize>,
+}
+
+impl NemhauserUllmann {
+    /// Pareto frontier over all capacities, independently of the bag size.
+    pub fn frontier<I, C, W>(&self, instance: &Instance<I, C, W>) -> ParetoFrontier<C, W>
+    where
+        I: Item<C, W>,
+        C: Numeric,
+        W: Numeric,
+    {
+        NemhauserUllmann::pareto_states(instance, None)
+    }
+
+    fn pareto_states<I, C, W>(
+        instance: &Instance<I, C, W>,
+        limit: Option<W>,
+    ) -> ParetoFrontier<C, W>
+    where
+        I: Item<C, W>,
+        C: Numeric,
+        W: Numeric,
+    {
+        let mut nodes: Vec<(usize, Option<usize>)> = Vec::new();
+        let mut states = vec![State {
+            weight: W::zero(),
+            cost: C::zero(),
+            node: None,
+        }];
+        for (index, item) in instance.items().iter().enumerate() {
+            if *item.cost() <= C::zero() {
+                continue;
+            }
+            let shifted: Vec<State<C, W>> = states
+                .iter()
+                .map(|state| State {
+                    weight: state.weight + *item.weight(),
+                    cost: state.cost + *item.cost(),
+                    node: state.node,
+                })
+                .filter(|state| match limit {
+                    Some(limit) => state.weight <= limit,
+                    None => true,
+                })
+                .collect();
+
+            let mut merged: Vec<State<C, W>> = Vec::with_capacity(states.len() + shifted.len());
+            let (mut old, mut new) = (states.iter().peekable(), shifted.into_iter().peekable());
+            loop {
+                let take_new = match (old.peek(), new.peek()) {
+                    (None, None) => break,
+                    (Some(_), None) => false,
+                    (None, Some(_)) => true,
+                    (Some(a), Some(b)) => {
+                        b.weight < a.weight || (b.weight == a.weight && b.cost > a.cost)
+                    }
+                };
+                let state = if take_new {
+                    let mut state = new.next().unwrap();
+                    if matches!(merged.last(), Some(last) if last.cost >= state.cost) {
+                        continue;
+                    }
+                    nodes.push((index, state.node));
+                    state.node = Some(nodes.len() - 1);
+                    state
+                } else {
+                    *old.next().unwrap()
+                };
+                if !matches!(merged.last(), Some(last) if last.cost >= state.cost) {
+                    merged.push(state);
+                }
+            }
+            states = merged;
+        }
+
+        let points = states
+            .into_iter()
+            .map(|state| {
+                let mut packed_items = Vec::new();
+                let mut node = state.node;
+                while let Some(current) = node {
+                    packed_items.push(nodes[current].0);
+                    node = nodes[current].1;
+                }
+                packed_items.reverse();
+                ParetoPoint {
+                    weight: state.weight,
+                    cost: state.cost,
+                    packed_items,
+                }
+            })
+            .collect();
+        ParetoFrontier { points }
+    }
+}
+
+impl<I, C, W> Algorithm<Instance<I, C, W>> for NemhauserUllmann
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn run(&self, instance: &Instance<I, C, W>) -> Solution {
+        NemhauserUllmann::pareto_states(instance, Some(*instance.bag_size()))
+            .solution(*instance.bag_size())
+    }
+}
+
+#[cfg(test)]
+mod test_super {
+    use super::*;
+    use rand::distributions::Uniform;
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn solving_by_greedy_works() {
+        let instance = Instance::from((vec![(1, 2), (2, 3), (2, 1)], 5));
+        let solution = instance.run(Greedy);
+        assert!(solution.is_solved());
+        let items = solution.as_solution().unwrap();
+        assert_eq!(items, vec![1, 2])
+    }
+
+    #[test]
+    fn random_validation_greedy_alg() {
+        let mut rng = thread_rng();
+        let costs: Vec<u32> = rng.sample_iter(Uniform::new(0, 100)).take(30).collect();
+        let weights: Vec<u32> = rng.sample_iter(Uniform::new(1, 100)).take(30).collect();
+        let size = rng.sample(Uniform::new(400, 700));
+        let instance = Instance::from((costs, weights, size));
+        let solution = instance.run(Greedy);
+        assert!(solution.is_solved());
+        assert!(Greedy.is_correct(&instance, &solution));
+    }
+
+    #[test]
+    fn solving_by_simple_dp_works() {
+        let instance = Instance::from((vec![(1, 2), (2, 3), (2, 1)], 5));
+        let solution = instance.run(SimpleDP);
+        assert!(solution.is_solved());
+        let items = solution.as_solution().unwrap();
+        assert_eq!(items, vec![1, 2])
+    }
+
+    #[test]
+    fn random_validation_simple_dp_alg() {
+        let mut rng = thread_rng();
+        let costs: Vec<u32> = rng.sample_iter(Uniform::new(0, 100)).take(30).collect();
+        let weights: Vec<u32> = rng.sample_iter(Uniform::new(1, 100)).take(30).collect();
+        let size: u32 = rng.sample(Uniform::new(400, 700));
+        let instance = Instance::from((costs, weights, size));
+        let dp_solution = instance.run(SimpleDP);
+        let ilp_solution =
+            instance.solve_by_reduction(&branch_and_bound::BranchAndBound::default());
+        assert!(dp_solution.is_solved());
+        assert!(ilp_solution.is_solved());
+        assert_eq!(ilp_solution.cost(&instance), dp_solution.cost(&instance));
+    }
+
+    #[test]
+    fn solving_by_fptas_works() {
+        let instance = Instance::from((vec![(1, 2), (2, 3), (2, 1)], 5));
+        let solution = instance.run(FPTAS::new(0.5));
+        assert!(solution.is_solved());
+        let items = solution.as_solution().unwrap();
+        assert_eq!(items, vec![1, 2]);
+
+        let instance = Instance::from((Vec::<(u32, u32)>::new(), 5));
+        assert_eq!(Some(vec![]), instance.run(FPTAS::new(0.5)).as_solution());
+
+        let instance = Instance::from((
+            vec![(3, 2_000_000_000u32), (5, 1_000_000_000)],
+            4_000_000_000,
+        ));
+        assert_eq!(
+            Some(vec![0, 1]),
+            instance.run(FPTAS::new(0.1)).as_solution()
+        );
+    }
+
+    #[test]
+    fn random_validation_fptas_alg() {
+        let mut rng = thread_rng();
+        let costs: Vec<u32> = rng.sample_iter(Uniform::new(0, 100)).take(30).collect();
+        let weights: Vec<u32> = rng.sample_iter(Uniform::new(1, 100)).take(30).collect();
+        let size: u32 = rng.sample(Uniform::new(400, 700));
+        let eps: f64 = rng.sample(Uniform::new(0.05, 0.95));
+        let instance = Instance::from((costs, weights, size));
+        let solution = instance.run(FPTAS::new(eps));
+        assert!(solution.is_solved());
+        assert!(FPTAS::new(eps).is_correct(&instance, &solution));
+    }
+
+    #[test]
+    fn solving_by_branch_and_bound_works() {
+        let instance = Instance::from((vec![(1, 2), (2, 3), (2, 1)], 5));
+        for bound in [UpperBound::Dantzig, UpperBound::MartelloToth].iter() {
+            let solution = instance.run(BranchAndBound::new(*bound));
+            assert_eq!(Some(vec![1, 2]), solution.as_solution());
+        }
+    }
+
+    #[test]
+    fn random_validation_branch_and_bound_alg() {
+        let mut rng = thread_rng();
+        let costs: Vec<u32> = rng.sample_iter(Uniform::new(0, 100)).take(30).collect();
+        let weights: Vec<u32> = rng.sample_iter(Uniform::new(1, 100)).take(30).collect();
+        let size: u32 = rng.sample(Uniform::new(400, 700));
+        let instance = Instance::from((costs, weights, size));
+        let dp_solution = instance.run(SimpleDP);
+        for bound in [UpperBound::Dantzig, UpperBound::MartelloToth].iter() {
+            let solution = instance.run(BranchAndBound::new(*bound));
+            assert_eq!(dp_solution.cost(&instance), solution.cost(&instance));
+        }
+
+        let costs: Vec<f64> = rng.sample_iter(Uniform::new(0.0, 10.0)).take(30).collect();
+        let weights: Vec<f64> = rng.sample_iter(Uniform::new(0.5, 10.0)).take(30).collect();
+        let instance = Instance::from((costs, weights, rng.sample(Uniform::new(40.0, 70.0))));
+        let solution = instance.run(BranchAndBound::default());
+        assert!(BranchAndBound::default().is_correct(&instance, &solution));
+    }
+
+    #[test]
+    fn solving_by_core_works() {
+        let instance = Instance::from((vec![(1, 2), (2, 3), (2, 1)], 5));
+        for strategy in [CoreStrategy::ExpKnap, CoreStrategy::MinKnap].iter() {
+            let (solution, statistics) = Core::new(*strategy).solve(&instance);
+            assert_eq!(Some(vec![1, 2]), solution.as_solution());
+            assert_eq!(2, statistics.break_item());
+        }
+    }
+
+    #[test]
+    fn random_validation_core_alg() {
+        let mut rng = thread_rng();
+        let costs: Vec<u32> = rng.sample_iter(Uniform::new(0, 100)).take(30).collect();
+        let weights: Vec<u32> = rng.sample_iter(Uniform::new(1, 100)).take(30).collect();
+        let size: u32 = rng.sample(Uniform::new(400, 700));
+        let instance = Instance::from((costs, weights, size));
+        let dp_solution = instance.run(SimpleDP);
+        for strategy in [CoreStrategy::ExpKnap, CoreStrategy::MinKnap].iter() {
+            let solution = instance.run(Core::new(*strategy));
+            assert_eq!(dp_solution.cost(&instance), solution.cost(&instance));
+        }
+    }
+
+    #[test]
+    fn core_solves_large_instances() {
+        let rng = thread_rng();
+        let costs: Vec<u32> = rng.sample_iter(Uniform::new(1, 1000)).take(2000).collect();
+        let weights: Vec<u32> = rng.sample_iter(Uniform::new(1, 1000)).take(2000).collect();
+        let instance = Instance::from((costs, weights, 500_000));
+        let (expknap, expknap_statistics) = Core::new(CoreStrategy::ExpKnap).solve(&instance);
+        let (minknap, minknap_statistics) = Core::new(CoreStrategy::MinKnap).solve(&instance);
+        assert_eq!(expknap.cost(&instance), minknap.cost(&instance));
+        assert_eq!(
+            expknap_statistics.break_item(),
+            minknap_statistics.break_item()
+        );
+        assert!(minknap_statistics.core_size() <= 2000);
+        assert!(minknap_statistics.states() > 0);
+    }
+
+    #[test]
+    fn solving_by_meet_in_the_middle_works() {
+        let instance = Instance::from((vec![(1, 2), (2, 3), (2, 1)], 5));
+        for schroeppel_shamir in [false, true].iter() {
+            let algorithm = MeetInTheMiddle::new().with_schroeppel_shamir(*schroeppel_shamir);
+            assert_eq!(Some(vec![1, 2]), instance.run(algorithm).as_solution());
+        }
+    }
+
+    #[test]
+    fn random_validation_meet_in_the_middle_alg() {
+        let mut rng = thread_rng();
+        let costs: Vec<u32> = rng.sample_iter(Uniform::new(0, 100)).take(16).collect();
+        let weights: Vec<u32> = rng.sample_iter(Uniform::new(1, 100)).take(16).collect();
+        let size: u32 = rng.sample(Uniform::new(0, 700));
+        let instance = Instance::from((costs, weights, size));
+        let dp_solution = instance.run(SimpleDP);
+        for schroeppel_shamir in [false, true].iter() {
+            let algorithm = MeetInTheMiddle::new().with_schroeppel_shamir(*schroeppel_shamir);
+            let solution = instance.run(algorithm);
+            assert_eq!(dp_solution.cost(&instance), solution.cost(&instance));
+        }
+    }
+
+    #[test]
+    fn meet_in_the_middle_handles_huge_weights() {
+        let rng = thread_rng();
+        let costs: Vec<f64> = rng.sample_iter(Uniform::new(0.0, 1e3)).take(24).collect();
+        let weights: Vec<f64> = rng.sample_iter(Uniform::new(1e12, 1e14)).take(24).collect();
+        let instance = Instance::from((costs, weights, 6e14));
+        let expected = instance
+            .run(BranchAndBound::default())
+            .cost(&instance)
+            .unwrap();
+        for schroeppel_shamir in [false, true].iter() {
+            let algorithm = MeetInTheMiddle::new().with_schroeppel_shamir(*schroeppel_shamir);
+            let cost = instance.run(algorithm).cost(&instance).unwrap();
+            assert!((expected - cost).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn solving_by_profit_dp_works() {
+        let instance = Instance::from((vec![(1, 2), (2, 3), (2, 1)], 5));
+        assert_eq!(Some(vec![1, 2]), instance.run(ProfitDP).as_solution());
+
+        let instance = Instance::from((vec![(1.0, 2.5), (2.0, 3.5), (2.0, 1.5)], 5.0));
+        assert_eq!(Some(vec![1, 2]), instance.run(ProfitDP).as_solution());
+
+        let instance = Instance::from((vec![(1.5, 2.0)], 5.0));
+        assert!(!instance.run(ProfitDP).is_solved());
+
+        let instance = Instance::from((vec![(1e20, 2.0)], 5.0));
+        assert!(!instance.run(ProfitDP).is_solved());
+    }
+
+    #[test]
+    fn profit_dp_bounds_its_table() {
+        let instance = Instance::from((vec![(1e12, 9.0), (3.0, 2.0), (4.0, 3.0)], 5.0));
+        assert_eq!(Some(vec![1, 2]), instance.run(ProfitDP).as_solution());
+
+        let instance = Instance::from((vec![(1u32 << 30, 1), (1 << 30, 1)], 2));
+        assert!(!instance.run(ProfitDP).is_solved());
+    }
+
+    #[test]
+    fn random_validation_profit_dp_alg() {
+        let mut rng = thread_rng();
+        let costs: Vec<u32> = rng.sample_iter(Uniform::new(0, 100)).take(30).collect();
+        let weights: Vec<u32> = rng.sample_iter(Uniform::new(1, 100)).take(30).collect();
+        let size: u32 = rng.sample(Uniform::new(400, 700));
+        let instance = Instance::from((costs, weights, size));
+        let dp_solution = instance.run(SimpleDP);
+        assert_eq!(
+            dp_solution.cost(&instance),
+            instance.run(ProfitDP).cost(&instance)
+        );
+
+        let costs: Vec<f64> = rng
+            .sample_iter(Uniform::new(0, 100))
+            .take(30)
+            .map(f64::from)
+            .collect();
+        let weights: Vec<f64> = rng.sample_iter(Uniform::new(0.5, 10.0)).take(30).collect();
+        let instance = Instance::from((costs, weights, rng.sample(Uniform::new(40.0, 70.0))));
+        let solution = instance.run(ProfitDP);
+        assert!(BranchAndBound::default().is_correct(&instance, &solution));
+    }
+
+    #[test]
+    #[should_panic(expected = "Epsilon must lie in (0, 1]!")]
+    fn fptas_rejects_zero_eps() {
+        FPTAS::new(0.0);
+    }
+
+    #[test]
+    fn random_validation_fptas_alg_with_float_weights() {
+        let mut rng = thread_rng();
+        let costs: Vec<f64> = rng.sample_iter(Uniform::new(0.0, 100.0)).take(30).collect();
+        let weights: Vec<f64> = rng.sample_iter(Uniform::new(0.5, 10.0)).take(30).collect();
+        let eps: f64 = rng.sample(Uniform::new(0.05, 0.95));
+        let instance = Instance::from((costs, weights, rng.sample(Uniform::new(40.0, 70.0))));
+        let solution = instance.run(FPTAS::new(eps));
+        assert!(solution.is_solved());
+        assert!(FPTAS::new(eps).is_correct(&instance, &solution));
+    }
+
+    #[test]
+    fn pareto_frontier_works() {
+        let instance = Instance::from((vec![(3, 2), (4, 3), (2, 1), (1, 3)], 4));
+        assert_eq!(
+            Some(vec![1, 2]),
+            instance.run(NemhauserUllmann).as_solution()
+        );
+
+        let frontier = NemhauserUllmann.frontier(&instance);
+        let points: Vec<(u32, u32)> = frontier
+            .points()
+            .iter()
+            .map(|point| (point.weight(), point.cost()))
+            .collect();
+        assert_eq!(
+            vec![
+                (0, 0),
+                (1, 2),
+                (2, 3),
+                (3, 5),
+                (4, 6),
+                (5, 7),
+                (6, 9),
+                (9, 10)
+            ],
+            points
+        );
+        assert_eq!(
+            Some(&[][..]),
+            frontier.best_within(0).map(|p| p.packed_items())
+        );
+        assert_eq!(9, frontier.best_within(8).unwrap().cost());
+        assert_eq!(
+            &[0, 1, 2, 3],
+            frontier.best_within(9).unwrap().packed_items()
+        );
+    }
+
+    #[test]
+    fn random_validation_nemhauser_ullmann_alg() {
+        let mut rng = thread_rng();
+        let costs: Vec<u32> = rng.sample_iter(Uniform::new(0, 100)).take(40).collect();
+        let weights: Vec<u32> = rng.sample_iter(Uniform::new(1, 100)).take(40).collect();
+        let size: u32 = rng.sample(Uniform::new(400, 900));
+        let instance = Instance::from((costs, weights, size));
+        let dp_solution = instance.run(SimpleDP);
+        let solution = instance.run(NemhauserUllmann);
+        assert!(solution.is_solved());
+        assert_eq!(dp_solution.cost(&instance), solution.cost(&instance));
+
+        let frontier = NemhauserUllmann.frontier(&instance);
+        for point in frontier.points() {
+            let packed: u32 = point
+                .packed_items()
+                .iter()
+                .map(|&i| *instance.items()[i].weight())
+                .sum();
+            assert_eq!(point.weight(), packed);
+        }
+        assert_eq!(
+            dp_solution.cost(&instance),
+            frontier.best_within(size).map(|point| point.cost())
+        );
+    }
+
+    #[test]
+    fn solving_by_hirschberg_dp_works() {
+        let instance = Instance::from((vec![(1, 2), (2, 3), (2, 1)], 5));
+        assert_eq!(Some(vec![1, 2]), instance.run(HirschbergDP).as_solution());
+
+        let instance = Instance::from((Vec::<(u32, u32)>::new(), 5));
+        assert_eq!(Some(vec![]), instance.run(HirschbergDP).as_solution());
+    }
+
+    #[test]
+    fn random_validation_hirschberg_dp_alg() {
+        let mut rng = thread_rng();
+        let costs: Vec<u32> = rng.sample_iter(Uniform::new(0, 8)).take(40).collect();
+        let weights: Vec<u32> = rng.sample_iter(Uniform::new(1, 30)).take(40).collect();
+        let size: u32 = rng.sample(Uniform::new(0, 300));
+        let instance = Instance::from((costs, weights, size));
+        assert_eq!(
+            instance.run(SimpleDP).as_solution(),
+            instance.run(HirschbergDP).as_solution()
+        );
+    }
+
+    #[test]
+    fn batch_solving_keeps_order() {
+        let mut rng = thread_rng();
+        let instances: Vec<Instance<_, u32, u32>> = (0..20)
+            .map(|_| {
+                let costs: Vec<u32> = rng.sample_iter(Uniform::new(0, 100)).take(30).collect();
+                let weights: Vec<u32> = rng.sample_iter(Uniform::new(1, 100)).take(30).collect();
+                Instance::from((costs, weights, rng.sample(Uniform::new(100, 10000))))
+            })
+            .collect();
+        let solutions = run_batch(&SimpleDP, &instances);
+        assert_eq!(instances.len(), solutions.len());
+        for (instance, solution) in instances.iter().zip(solutions) {
+            assert_eq!(instance.run(SimpleDP).as_solution(), solution.as_solution());
+        }
+    }
+
+    #[test]
+    fn solving_by_ptas_works() {
+        let instance = Instance::from((vec![(6, 2), (10, 5), (12, 5)], 10));
+        assert_eq!(Some(vec![0, 2]), instance.run(PTAS::new(0)).as_solution());
+        assert_eq!(Some(vec![0, 2]), instance.run(PTAS::new(1)).as_solution());
+        assert_eq!(Some(vec![1, 2]), instance.run(PTAS::new(2)).as_solution());
+
+        let instance = Instance::from((Vec::<(f64, f64)>::new(), 10.0));
+        assert_eq!(Some(vec![]), instance.run(PTAS::new(2)).as_solution());
+    }
+
+    #[test]
+    fn random_validation_ptas_alg() {
+        let mut rng = thread_rng();
+        let costs: Vec<f64> = rng.sample_iter(Uniform::new(0.0, 100.0)).take(25).collect();
+        let weights: Vec<f64> = rng.sample_iter(Uniform::new(0.5, 10.0)).take(25).collect();
+        let instance = Instance::from((costs, weights, rng.sample(Uniform::new(20.0, 60.0))));
+        for k in 0..3 {
+            let solution = instance.run(PTAS::new(k));
+            assert!(PTAS::new(k).is_correct(&instance, &solution));
+        }
     }
 }
@@ -0,0 +1,103 @@
+use crate::algorithm::Algorithm;
+use crate::knapsack::bounded::{chunks, multiple, Instance, Solution};
+use crate::knapsack::Item;
+use crate::primitives::Numeric;
+
+/// Dynamic program over the bag size that treats every binary chunk of copies of an
+/// item as a single 0-1 item, i.e. it takes `O(W * sum_i log(copies_i))` time.
+pub struct SimpleDP;
+
+impl<I, C> Algorithm<Instance<I, C, u32>> for SimpleDP
+where
+    I: Item<C, u32>,
+    C: Numeric,
+{
+    fn run(&self, instance: &Instance<I, C, u32>) -> Solution {
+        let size = *instance.bag_size();
+        let chunks: Vec<(usize, u32, C, u32)> = instance
+            .items()
+            .iter()
+            .zip(instance.copies().iter())
+            .enumerate()
+            .flat_map(|(index, (item, &copies))| {
+                chunks(copies).into_iter().filter_map(move |chunk| {
+                    let weight = u64::from(chunk) * u64::from(*item.weight());
+                    if weight > u64::from(size) {
+                        None
+                    } else {
+                        Some((index, chunk, multiple(*item.cost(), chunk), weight as u32))
+                    }
+                })
+            })
+            .collect();
+
+        let mut values = vec![vec![C::zero(); size as usize + 1]; chunks.len() + 1];
+        for (i, &(_, _, cost, weight)) in chunks.iter().enumerate() {
+            for j in 0..=size as usize {
+                values[i + 1][j] = values[i][j];
+                if weight as usize <= j {
+                    let packed = values[i][j - weight as usize] + cost;
+                    if packed > values[i + 1][j] {
+                        values[i + 1][j] = packed;
+                    }
+                }
+            }
+        }
+
+        let mut counts = vec![0; instance.number_of_items()];
+        let mut left_weight = size as usize;
+        for (i, &(index, chunk, _, weight)) in chunks.iter().enumerate().rev() {
+            if values[i + 1][left_weight] != values[i][left_weight] {
+                counts[index] += chunk;
+                left_weight -= weight as usize;
+            }
+        }
+        Solution::Solved { counts }
+    }
+}
+
+#[cfg(test)]
+mod test_super {
+    use super::*;
+    use crate::problem::{OptProblemKind, SolutionKind};
+    use crate::program::branch_and_bound::BranchAndBound;
+    use crate::reduction::Reduction;
+    use rand::distributions::Uniform;
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn solving_by_simple_dp_works() {
+        let instance = Instance::from((vec![(3, 2, 3), (5, 3, 2), (1, 1, 10)], 10));
+        let solution = instance.run(SimpleDP);
+        assert_eq!(Some(16), solution.cost(&instance));
+        assert_eq!(Some(vec![2, 2, 0]), solution.as_solution());
+    }
+
+    #[test]
+    fn random_validation_simple_dp_alg() {
+        let mut rng = thread_rng();
+        let items: Vec<(u32, u32, u32)> = (0..15)
+            .map(|_| {
+                (
+                    rng.sample(Uniform::new(0, 100)),
+                    rng.sample(Uniform::new(1, 100)),
+                    rng.sample(Uniform::new(0, 8)),
+                )
+            })
+            .collect();
+        let size: u32 = rng.sample(Uniform::new(400, 700));
+        let instance = Instance::from((items, size));
+        let dp_solution = instance.run(SimpleDP);
+        let ilp_solution = instance.solve_by_reduction(&BranchAndBound::default());
+        assert!(dp_solution.is_solved());
+        assert!(ilp_solution.is_solved());
+        assert_eq!(ilp_solution.cost(&instance), dp_solution.cost(&instance));
+        let weight: u32 = instance
+            .items()
+            .iter()
+            .zip(dp_solution.as_solution().unwrap())
+            .map(|(item, count)| item.weight() * count)
+            .sum();
+        assert!(weight <= size);
+    }
+}
@@ -0,0 +1,200 @@
+use crate::knapsack::{DefaultItem, Item};
+use crate::primitives::Numeric;
+use crate::problem::{OptProblemKind, SolutionKind};
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+pub mod algorithms;
+pub mod reductions;
+
+/// Knapsack instance where item `i` may be packed up to `copies[i]` times.
+#[derive(Clone, Debug)]
+pub struct Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    items: Vec<I>,
+    copies: Vec<u32>,
+    size: W,
+    cost_type: PhantomData<C>,
+}
+
+impl<I, C, W> Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    pub fn new(items: Vec<I>, copies: Vec<u32>, size: W) -> Self {
+        if items.iter().any(|item| *item.weight() <= W::zero()) {
+            panic!("Item weights must be positive!");
+        }
+        if items.len() != copies.len() {
+            panic!("Every item needs a number of copies!");
+        }
+        Instance {
+            items,
+            copies,
+            size,
+            cost_type: PhantomData,
+        }
+    }
+
+    pub fn items(&self) -> &[I] {
+        &self.items
+    }
+
+    pub fn copies(&self) -> &[u32] {
+        &self.copies
+    }
+
+    pub fn number_of_items(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn bag_size(&self) -> &W {
+        &self.size
+    }
+}
+
+impl<I, C, W> Display for Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Bounded knapsack instance\n   - size = {}\n   - items = {{ {} }}",
+            self.size,
+            self.items
+                .iter()
+                .zip(self.copies.iter())
+                .map(|(item, copies)| format!("{} x {}", copies, item))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+impl<I, C, W> OptProblemKind for Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    type Solution = Solution;
+    type Cost = C;
+}
+
+#[derive(Clone, Debug)]
+pub enum Solution {
+    /// `counts[i]` is the number of packed copies of item `i`.
+    Solved {
+        counts: Vec<u32>,
+    },
+    Infeasible,
+    Failed(String),
+}
+
+impl Solution {
+    pub fn is_solved(&self) -> bool {
+        matches!(self, Solution::Solved { .. })
+    }
+
+    pub fn as_solution(self) -> Option<Vec<u32>> {
+        match self {
+            Solution::Solved { counts } => Some(counts),
+            _ => None,
+        }
+    }
+}
+
+impl<I, C, W> SolutionKind<Instance<I, C, W>> for Solution
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn cost(&self, instance: &Instance<I, C, W>) -> Option<C> {
+        match self {
+            Solution::Infeasible | Solution::Failed(_) => None,
+            Solution::Solved { counts } => Some(
+                instance
+                    .items()
+                    .iter()
+                    .zip(counts.iter())
+                    .map(|(item, &count)| multiple(*item.cost(), count))
+                    .sum(),
+            ),
+        }
+    }
+}
+
+/// `count` times `value`, computed by doubling since [`Numeric`] has no multiplication.
+pub(super) fn multiple<T: Numeric>(value: T, count: u32) -> T {
+    let (mut result, mut power, mut count) = (T::zero(), value, count);
+    while count > 0 {
+        if count & 1 == 1 {
+            result += power;
+        }
+        count >>= 1;
+        if count > 0 {
+            power = power + power;
+        }
+    }
+    result
+}
+
+/// Splits `copies` into the chunk sizes `1, 2, 4, ..., 2^k, r` whose subsets sum up to
+/// every number of copies between zero and `copies`.
+fn chunks(copies: u32) -> Vec<u32> {
+    let mut chunks = Vec::new();
+    let (mut left, mut size) = (copies, 1);
+    while left > 0 {
+        let chunk = size.min(left);
+        chunks.push(chunk);
+        left -= chunk;
+        size = size.saturating_mul(2);
+    }
+    chunks
+}
+
+impl<T> From<(Vec<(T, T, u32)>, T)> for Instance<DefaultItem<T>, T, T>
+where
+    T: Numeric,
+{
+    fn from(input: (Vec<(T, T, u32)>, T)) -> Self {
+        let (items, copies) = input
+            .0
+            .into_iter()
+            .map(|(cost, weight, copies)| (DefaultItem::from((cost, weight)), copies))
+            .unzip();
+        Instance::new(items, copies, input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_instance_from_works() {
+        let instance = Instance::from((vec![(1, 2, 3), (2, 3, 1)], 5));
+        assert_eq!(2, instance.number_of_items());
+        assert_eq!(&[3, 1], instance.copies());
+        let solution = Solution::Solved { counts: vec![2, 1] };
+        assert_eq!(Some(4), solution.cost(&instance));
+    }
+
+    #[test]
+    fn binary_splitting_works() {
+        assert_eq!(Vec::<u32>::new(), chunks(0));
+        assert_eq!(vec![1, 2, 4, 3], chunks(10));
+        assert_eq!(vec![1, 2, 4], chunks(7));
+        assert_eq!(12, multiple(4, 3));
+    }
+}
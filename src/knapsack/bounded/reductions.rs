@@ -0,0 +1,174 @@
+use crate::knapsack::bounded::{chunks, multiple, Instance, Solution};
+use crate::knapsack::reductions::{decode_solution, IlpSolution};
+use crate::knapsack::{self, DefaultItem, Item};
+use crate::primitives::Numeric;
+use crate::program::model::{LinearExpr, Sense, Variable};
+use crate::program::{LpSolution, MathProgram};
+use crate::reduction::Reduction;
+
+impl<I, C, W> Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn item_variables(&self, program: &mut MathProgram) -> Vec<Variable> {
+        self.copies
+            .iter()
+            .enumerate()
+            .map(|(index, &copies)| {
+                program.add_integer(&format!("x_{}", index), Some(0.0), Some(copies.into()))
+            })
+            .collect()
+    }
+
+    /// Item index and number of copies of every item of the 0-1 instance. Chunks that
+    /// are heavier than the bag are left out, so their weight is never computed.
+    fn binary_chunks(&self) -> Vec<(usize, u32)> {
+        let size: f64 = self.size.into();
+        self.copies
+            .iter()
+            .enumerate()
+            .flat_map(|(index, &copies)| {
+                let weight: f64 = (*self.items[index].weight()).into();
+                chunks(copies)
+                    .into_iter()
+                    .filter(move |&chunk| f64::from(chunk) * weight <= size)
+                    .map(move |chunk| (index, chunk))
+            })
+            .collect()
+    }
+}
+
+/// Binary splitting: `b` copies of an item become items of `1, 2, 4, ...` copies each.
+impl<I, T> Reduction<knapsack::Instance<DefaultItem<T>, T, T>> for Instance<I, T, T>
+where
+    I: Item<T, T>,
+    T: Numeric,
+{
+    fn reduce_instance(&self) -> knapsack::Instance<DefaultItem<T>, T, T> {
+        let items = self
+            .binary_chunks()
+            .into_iter()
+            .map(|(index, chunk)| {
+                let item = &self.items[index];
+                DefaultItem {
+                    cost: multiple(*item.cost(), chunk),
+                    weight: multiple(*item.weight(), chunk),
+                }
+            })
+            .collect();
+        knapsack::Instance::new(items, self.size)
+    }
+
+    fn reduce_solution(&self, solution: &knapsack::Solution) -> Solution {
+        match solution {
+            knapsack::Solution::Solved { packed_items } => {
+                let chunks = self.binary_chunks();
+                let mut counts = vec![0; self.number_of_items()];
+                for &packed in packed_items {
+                    let (index, chunk) = chunks[packed];
+                    counts[index] += chunk;
+                }
+                Solution::Solved { counts }
+            }
+            knapsack::Solution::Infeasible => Solution::Infeasible,
+            knapsack::Solution::Failed(msg) => Solution::Failed(msg.clone()),
+        }
+    }
+}
+
+impl<I, C, W> Reduction<MathProgram> for Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn reduce_instance(&self) -> MathProgram {
+        let mut model = MathProgram::new("bounded_knapsack", Sense::Maximize);
+        let vars = self.item_variables(&mut model);
+
+        model.set_objective(
+            self.items
+                .iter()
+                .zip(vars.iter())
+                .map(|(item, var)| (*item.cost()).into() * *var)
+                .sum::<LinearExpr>(),
+        );
+        model.add_constraint(
+            self.items
+                .iter()
+                .zip(vars.iter())
+                .map(|(item, var)| (*item.weight()).into() * *var)
+                .sum::<LinearExpr>()
+                .le(self.size.into()),
+        );
+
+        model
+    }
+
+    fn reduce_solution(&self, solution: &LpSolution) -> Solution {
+        decode_solution(solution, "bounded knapsack", self.items.len(), |values| {
+            Solution::Solved {
+                counts: values
+                    .into_iter()
+                    .map(|value| value.max(0.0) as u32)
+                    .collect(),
+            }
+        })
+    }
+}
+
+impl IlpSolution for Solution {
+    fn infeasible() -> Self {
+        Solution::Infeasible
+    }
+
+    fn failed(message: String) -> Self {
+        Solution::Failed(message)
+    }
+}
+
+#[cfg(test)]
+mod test_super {
+    use super::*;
+    use crate::knapsack::algorithms::SimpleDP;
+    use crate::problem::SolutionKind;
+    use crate::program::branch_and_bound::BranchAndBound;
+    use crate::program::model::VariableKind;
+
+    #[test]
+    fn binary_splitting_reduction_works() {
+        let instance = Instance::from((vec![(3, 2, 10), (5, 3, 2)], 10));
+        let reduced: knapsack::Instance<DefaultItem<u32>, u32, u32> = instance.reduce_instance();
+        assert_eq!(6, reduced.number_of_items());
+        assert_eq!(&6, reduced.items()[3].weight());
+
+        let solution: Solution = instance.solve_by_reduction(&SimpleDP);
+        assert_eq!(Some(16), solution.cost(&instance));
+        assert_eq!(Some(vec![2, 2]), solution.as_solution());
+    }
+
+    #[test]
+    fn binary_splitting_skips_chunks_heavier_than_the_bag() {
+        let instance = Instance::from((vec![(1u32, 1u32 << 30, 8), (2, 3, 5)], 10));
+        let reduced: knapsack::Instance<DefaultItem<u32>, u32, u32> = instance.reduce_instance();
+        assert_eq!(3, reduced.number_of_items());
+
+        let solution: Solution = instance.solve_by_reduction(&SimpleDP);
+        assert_eq!(Some(vec![0, 3]), solution.clone().as_solution());
+        assert_eq!(Some(6), solution.cost(&instance));
+    }
+
+    #[test]
+    fn ilp_reduction_works() {
+        let instance = Instance::from((vec![(3.0, 2.0, 3), (5.0, 3.0, 2), (1.0, 1.0, 10)], 10.0));
+        let program: MathProgram = instance.reduce_instance();
+        let var = program.variables().next().unwrap();
+        assert_eq!(VariableKind::Integer, program.variable(var).kind());
+        assert_eq!(Some(3.0), program.variable(var).upper());
+
+        let solution: Solution = instance.solve_by_reduction(&BranchAndBound::default());
+        assert_eq!(Some(16.0), solution.cost(&instance));
+    }
+}
@@ -0,0 +1,308 @@
+use crate::algorithm::*;
+use crate::knapsack::conflict::Instance;
+use crate::knapsack::{Item, Solution};
+use crate::primitives::Numeric;
+use crate::problem::SolutionKind;
+use crate::program::branch_and_bound;
+use crate::reduction::Reduction;
+
+/// Item indices by non-increasing ratio of cost and weight.
+fn by_efficiency<I, C, W>(instance: &Instance<I, C, W>) -> Vec<usize>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    let ratio = |index: usize| {
+        let item = &instance.items()[index];
+        (*item.cost()).into() / (*item.weight()).into()
+    };
+    let mut order: Vec<usize> = (0..instance.number_of_items()).collect();
+    order.sort_by(|&a, &b| ratio(b).partial_cmp(&ratio(a)).unwrap());
+    order
+}
+
+/// Flags packings that exceed the bag or contain two conflicting items.
+fn validate_packing<I, C, W>(instance: &Instance<I, C, W>, solution: &Solution) -> Option<String>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    match solution {
+        Solution::Solved { packed_items } => instance.violation(packed_items),
+        _ => None,
+    }
+}
+
+/// Packs the items by decreasing efficiency whenever they fit and do not conflict with
+/// an already packed item. As for the classic greedy, the best single item is returned
+/// instead if it is worth more.
+pub struct Greedy;
+
+impl<I, C, W> Algorithm<Instance<I, C, W>> for Greedy
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn run(&self, instance: &Instance<I, C, W>) -> Solution {
+        let mut weight = W::zero();
+        let mut cost = C::zero();
+        let mut blocked = vec![false; instance.number_of_items()];
+        let mut packed = Vec::new();
+        for index in by_efficiency(instance) {
+            let item = &instance.items()[index];
+            if blocked[index] || weight + *item.weight() > *instance.bag_size() {
+                continue;
+            }
+            weight += *item.weight();
+            cost += *item.cost();
+            packed.push(index);
+            for &neighbor in instance.neighbors(index) {
+                blocked[neighbor] = true;
+            }
+        }
+        packed.sort_unstable();
+
+        let best_single = (0..instance.number_of_items())
+            .filter(|&index| *instance.items()[index].weight() <= *instance.bag_size())
+            .fold(None, |best: Option<usize>, index| match best {
+                Some(best) if *instance.items()[best].cost() >= *instance.items()[index].cost() => {
+                    Some(best)
+                }
+                _ => Some(index),
+            });
+        match best_single {
+            Some(index) if *instance.items()[index].cost() > cost => Solution::Solved {
+                packed_items: vec![index],
+            },
+            _ => Solution::Solved {
+                packed_items: packed,
+            },
+        }
+    }
+}
+
+impl<I, C, W> TheoreticValidation<Instance<I, C, W>> for Greedy
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn validate(&self, instance: &Instance<I, C, W>, solution: &Solution) -> TheoreticGuarantee {
+        match validate_packing(instance, solution) {
+            Some(violation) => TheoreticGuarantee::Inconsistent(violation),
+            None if solution.is_solved() => TheoreticGuarantee::Consistent,
+            None => TheoreticGuarantee::Failed(String::from(
+                "Error: Greedy algorithm did not return a packing!",
+            )),
+        }
+    }
+}
+
+/// Depth-first branch and bound over the items by decreasing efficiency. Packing an
+/// item excludes its neighbors, and every node is bounded by the fractional knapsack
+/// of the items that are still allowed.
+pub struct BranchAndBound;
+
+struct Search<'a, I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    instance: &'a Instance<I, C, W>,
+    order: Vec<usize>,
+    /// Number of packed neighbors per item.
+    blocked: Vec<usize>,
+    packed: Vec<usize>,
+    best: Vec<usize>,
+    best_cost: f64,
+}
+
+impl<'a, I, C, W> Search<'a, I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn upper_bound(&self, depth: usize, weight: W) -> f64 {
+        let mut capacity = (*self.instance.bag_size()).into() - weight.into();
+        let mut bound = 0.0;
+        for &index in &self.order[depth..] {
+            if self.blocked[index] > 0 {
+                continue;
+            }
+            let item = &self.instance.items()[index];
+            let (cost, item_weight): (f64, f64) = ((*item.cost()).into(), (*item.weight()).into());
+            if item_weight <= capacity {
+                capacity -= item_weight;
+                bound += cost;
+            } else {
+                bound += cost * capacity / item_weight;
+                break;
+            }
+        }
+        bound
+    }
+
+    fn explore(&mut self, depth: usize, weight: W, value: f64) {
+        if value > self.best_cost {
+            self.best_cost = value;
+            self.best = self.packed.clone();
+        }
+        if depth == self.order.len() || value + self.upper_bound(depth, weight) <= self.best_cost {
+            return;
+        }
+
+        let index = self.order[depth];
+        let item = &self.instance.items()[index];
+        if self.blocked[index] == 0 && weight + *item.weight() <= *self.instance.bag_size() {
+            for &neighbor in self.instance.neighbors(index) {
+                self.blocked[neighbor] += 1;
+            }
+            self.packed.push(index);
+            self.explore(
+                depth + 1,
+                weight + *item.weight(),
+                value + (*item.cost()).into(),
+            );
+            self.packed.pop();
+            for &neighbor in self.instance.neighbors(index) {
+                self.blocked[neighbor] -= 1;
+            }
+        }
+        self.explore(depth + 1, weight, value);
+    }
+}
+
+impl<I, C, W> Algorithm<Instance<I, C, W>> for BranchAndBound
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn run(&self, instance: &Instance<I, C, W>) -> Solution {
+        let mut search = Search {
+            instance,
+            order: by_efficiency(instance),
+            blocked: vec![0; instance.number_of_items()],
+            packed: Vec::new(),
+            best: Vec::new(),
+            best_cost: 0.0,
+        };
+        search.explore(0, W::zero(), 0.0);
+        let mut packed = search.best;
+        packed.sort_unstable();
+        Solution::Solved {
+            packed_items: packed,
+        }
+    }
+}
+
+impl<I, C, W> TheoreticValidation<Instance<I, C, W>> for BranchAndBound
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn validate(&self, instance: &Instance<I, C, W>, solution: &Solution) -> TheoreticGuarantee {
+        if let Some(violation) = validate_packing(instance, solution) {
+            return TheoreticGuarantee::Inconsistent(violation);
+        }
+        let optimal_solution =
+            instance.solve_by_reduction(&branch_and_bound::BranchAndBound::default());
+        match (solution.cost(instance), optimal_solution.cost(instance)) {
+            (Some(alg), Some(opt)) => {
+                if (alg.into() - opt.into()).abs() > 1e-6 {
+                    TheoreticGuarantee::Inconsistent(format!(
+                        "Branch and bound did not find an optimal solution: {} != {}",
+                        alg, opt
+                    ))
+                } else {
+                    TheoreticGuarantee::Consistent
+                }
+            }
+            _ => TheoreticGuarantee::Failed(String::from(
+                "Error: Cost of optimal solution and algorithm could not have been computed!",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_super {
+    use super::*;
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn greedy_works() {
+        let instance = Instance::from((
+            vec![(5, 3), (4, 2), (3, 2), (2, 1)],
+            5,
+            vec![(0, 1), (1, 3)],
+        ));
+        let solution = Greedy.run(&instance);
+        assert_eq!(Some(vec![1, 2]), solution.clone().as_solution());
+        assert!(Greedy.validate(&instance, &solution).is_correct());
+    }
+
+    #[test]
+    fn validation_flags_conflicts() {
+        let instance = Instance::from((
+            vec![(5, 3), (4, 2), (3, 2), (2, 1)],
+            5,
+            vec![(0, 1), (1, 3)],
+        ));
+        let solution = Solution::Solved {
+            packed_items: vec![1, 3],
+        };
+        assert!(!Greedy.validate(&instance, &solution).is_correct());
+        assert!(!BranchAndBound.validate(&instance, &solution).is_correct());
+    }
+
+    #[test]
+    fn random_validation_greedy_alg() {
+        let mut rng = thread_rng();
+        let items: Vec<(u32, u32)> = (0..30)
+            .map(|_| (rng.gen_range(1, 20), rng.gen_range(1, 10)))
+            .collect();
+        let conflicts: Vec<(usize, usize)> = (0..40)
+            .map(|_| (rng.gen_range(0, 30), rng.gen_range(0, 30)))
+            .filter(|(a, b)| a != b)
+            .collect();
+        let instance = Instance::from((items, rng.gen_range(20, 60), conflicts));
+        let solution = Greedy.run(&instance);
+        assert!(solution.is_solved());
+        assert!(Greedy.validate(&instance, &solution).is_correct());
+    }
+
+    #[test]
+    fn branch_and_bound_works() {
+        let instance = Instance::from((
+            vec![(5, 3), (4, 2), (3, 2), (2, 1)],
+            5,
+            vec![(0, 1), (1, 3)],
+        ));
+        let solution = BranchAndBound.run(&instance);
+        assert_eq!(Some(vec![0, 2]), solution.clone().as_solution());
+        assert_eq!(Some(8), solution.cost(&instance));
+    }
+
+    #[test]
+    fn random_validation_branch_and_bound_alg() {
+        let mut rng = thread_rng();
+        let items: Vec<(u32, u32)> = (0..12)
+            .map(|_| (rng.gen_range(1, 20), rng.gen_range(1, 10)))
+            .collect();
+        let conflicts: Vec<(usize, usize)> = (0..16)
+            .map(|_| (rng.gen_range(0, 12), rng.gen_range(0, 12)))
+            .filter(|(a, b)| a != b)
+            .collect();
+        let instance = Instance::from((items, rng.gen_range(10, 30), conflicts));
+        let solution = BranchAndBound.run(&instance);
+        assert!(solution.is_solved());
+        assert!(BranchAndBound.validate(&instance, &solution).is_correct());
+    }
+}
@@ -0,0 +1,253 @@
+use crate::knapsack::{DefaultItem, Item, Solution};
+use crate::primitives::Numeric;
+use crate::problem::{OptProblemKind, SolutionKind};
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+pub mod algorithms;
+pub mod reductions;
+
+/// Knapsack instance with a conflict graph over the item indices, items joined by an
+/// edge must not be packed together.
+#[derive(Clone, Debug)]
+pub struct Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    items: Vec<I>,
+    size: W,
+    neighbors: Vec<Vec<usize>>,
+    cost_type: PhantomData<C>,
+}
+
+impl<I, C, W> Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    pub fn new(items: Vec<I>, size: W, conflicts: Vec<(usize, usize)>) -> Self {
+        if items.iter().any(|item| *item.weight() <= W::zero()) {
+            panic!("Item weights must be positive!");
+        }
+        let mut neighbors = vec![Vec::new(); items.len()];
+        for (a, b) in conflicts {
+            if a >= items.len() || b >= items.len() {
+                panic!("Conflict ({}, {}) refers to a missing item!", a, b);
+            }
+            if a == b {
+                panic!("Item {} cannot conflict with itself!", a);
+            }
+            neighbors[a].push(b);
+            neighbors[b].push(a);
+        }
+        for list in neighbors.iter_mut() {
+            list.sort_unstable();
+            list.dedup();
+        }
+        Instance {
+            items,
+            size,
+            neighbors,
+            cost_type: PhantomData,
+        }
+    }
+
+    pub fn items(&self) -> &[I] {
+        &self.items
+    }
+
+    pub fn number_of_items(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn bag_size(&self) -> &W {
+        &self.size
+    }
+
+    /// Items in conflict with `item`, in increasing order.
+    pub fn neighbors(&self, item: usize) -> &[usize] {
+        &self.neighbors[item]
+    }
+
+    pub fn in_conflict(&self, a: usize, b: usize) -> bool {
+        self.neighbors[a].binary_search(&b).is_ok()
+    }
+
+    /// Every conflict once as `(a, b)` with `a < b`.
+    pub fn conflicts(&self) -> Vec<(usize, usize)> {
+        self.neighbors
+            .iter()
+            .enumerate()
+            .flat_map(|(a, list)| list.iter().filter(move |&&b| a < b).map(move |&b| (a, b)))
+            .collect()
+    }
+
+    /// Greedily grown maximal cliques such that every conflict lies in one of them.
+    pub fn clique_cover(&self) -> Vec<Vec<usize>> {
+        let mut covered: Vec<Vec<bool>> = self
+            .neighbors
+            .iter()
+            .map(|list| vec![false; list.len()])
+            .collect();
+        let mut cliques = Vec::new();
+        for (a, b) in self.conflicts() {
+            if covered[a][self.position(a, b)] {
+                continue;
+            }
+            let mut clique = vec![a, b];
+            for &candidate in &self.neighbors[a] {
+                if candidate != b
+                    && clique
+                        .iter()
+                        .all(|&member| self.in_conflict(member, candidate))
+                {
+                    clique.push(candidate);
+                }
+            }
+            for (i, &u) in clique.iter().enumerate() {
+                for &v in &clique[i + 1..] {
+                    let (pu, pv) = (self.position(u, v), self.position(v, u));
+                    covered[u][pu] = true;
+                    covered[v][pv] = true;
+                }
+            }
+            clique.sort_unstable();
+            cliques.push(clique);
+        }
+        cliques
+    }
+
+    fn position(&self, a: usize, b: usize) -> usize {
+        self.neighbors[a].binary_search(&b).unwrap()
+    }
+
+    /// Describes why `packed_items` is no valid packing, i.e. an item index out of
+    /// range, an exceeded bag or two conflicting items.
+    pub fn violation(&self, packed_items: &[usize]) -> Option<String> {
+        if let Some(&item) = packed_items.iter().find(|&&item| item >= self.items.len()) {
+            return Some(format!("Item {} does not exist", item));
+        }
+        let weight: W = packed_items
+            .iter()
+            .map(|&item| *self.items[item].weight())
+            .sum();
+        if weight > self.size {
+            return Some(format!(
+                "Packed weight {} exceeds the bag size {}",
+                weight, self.size
+            ));
+        }
+        for (i, &a) in packed_items.iter().enumerate() {
+            if let Some(&b) = packed_items[i + 1..]
+                .iter()
+                .find(|&&b| a == b || self.in_conflict(a, b))
+            {
+                return Some(format!("Items {} and {} cannot be packed together", a, b));
+            }
+        }
+        None
+    }
+
+    pub fn is_feasible(&self, packed_items: &[usize]) -> bool {
+        self.violation(packed_items).is_none()
+    }
+}
+
+impl<I, C, W> Display for Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Knapsack instance with conflicts\n   - size = {}\n   - items = {{ {} }}\n   - conflicts = {{ {} }}",
+            self.size,
+            self.items
+                .iter()
+                .map(|item| item.to_string())
+                .collect::<Vec<String>>()
+                .join(", "),
+            self.conflicts()
+                .iter()
+                .map(|(a, b)| format!("{}-{}", a, b))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+impl<I, C, W> OptProblemKind for Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    type Solution = Solution;
+    type Cost = C;
+}
+
+impl<I, C, W> SolutionKind<Instance<I, C, W>> for Solution
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn cost(&self, instance: &Instance<I, C, W>) -> Option<C> {
+        match self {
+            Solution::Infeasible | Solution::Failed(_) => None,
+            Solution::Solved { packed_items } => Some(
+                packed_items
+                    .iter()
+                    .map(|&item| *instance.items()[item].cost())
+                    .sum(),
+            ),
+        }
+    }
+}
+
+impl<T> From<(Vec<(T, T)>, T, Vec<(usize, usize)>)> for Instance<DefaultItem<T>, T, T>
+where
+    T: Numeric,
+{
+    fn from(input: (Vec<(T, T)>, T, Vec<(usize, usize)>)) -> Self {
+        let items = input.0.into_iter().map(DefaultItem::from).collect();
+        Instance::new(items, input.1, input.2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_instance_from_works() {
+        let instance = Instance::from((
+            vec![(1, 1), (2, 2), (3, 3), (4, 4)],
+            6,
+            vec![(0, 1), (2, 1), (1, 0)],
+        ));
+        assert_eq!(vec![(0, 1), (1, 2)], instance.conflicts());
+        assert_eq!(&[0, 2], instance.neighbors(1));
+        assert!(instance.is_feasible(&[0, 2]));
+        assert!(!instance.is_feasible(&[0, 1]));
+        assert!(!instance.is_feasible(&[2, 3]));
+    }
+
+    #[test]
+    fn clique_cover_works() {
+        let instance = Instance::from((
+            vec![(1, 1); 5],
+            3,
+            vec![(0, 1), (1, 2), (0, 2), (2, 3), (3, 4)],
+        ));
+        assert_eq!(
+            vec![vec![0, 1, 2], vec![2, 3], vec![3, 4]],
+            instance.clique_cover()
+        );
+    }
+}
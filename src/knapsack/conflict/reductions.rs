@@ -0,0 +1,103 @@
+use crate::knapsack::conflict::Instance;
+use crate::knapsack::reductions::{decode_solution, packed_items};
+use crate::knapsack::{Item, Solution};
+use crate::primitives::Numeric;
+use crate::program::model::{LinearExpr, Sense, Variable};
+use crate::program::{LpSolution, MathProgram};
+use crate::reduction::Reduction;
+
+impl<I, C, W> Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn item_variables(&self, program: &mut MathProgram) -> Vec<Variable> {
+        (0..self.items.len())
+            .map(|index| program.add_binary(&format!("x_{}", index)))
+            .collect()
+    }
+}
+
+/// Knapsack ILP with one constraint `sum x_i <= 1` per clique of
+/// [`clique_cover`](Instance::clique_cover), a clique of two items is an edge constraint.
+impl<I, C, W> Reduction<MathProgram> for Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn reduce_instance(&self) -> MathProgram {
+        let mut model = MathProgram::new("conflict_knapsack", Sense::Maximize);
+        let vars = self.item_variables(&mut model);
+
+        model.set_objective(
+            self.items
+                .iter()
+                .zip(vars.iter())
+                .map(|(item, var)| (*item.cost()).into() * *var)
+                .sum::<LinearExpr>(),
+        );
+        model.add_constraint(
+            self.items
+                .iter()
+                .zip(vars.iter())
+                .map(|(item, var)| (*item.weight()).into() * *var)
+                .sum::<LinearExpr>()
+                .le(self.size.into()),
+        );
+        for clique in self.clique_cover() {
+            model.add_constraint(
+                clique
+                    .iter()
+                    .map(|&item| LinearExpr::from(vars[item]))
+                    .sum::<LinearExpr>()
+                    .le(1.0),
+            );
+        }
+
+        model
+    }
+
+    fn reduce_solution(&self, solution: &LpSolution) -> Solution {
+        decode_solution(
+            solution,
+            "knapsack with conflicts",
+            self.items.len(),
+            |values| Solution::Solved {
+                packed_items: packed_items(&values),
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod test_super {
+    use super::*;
+    use crate::problem::SolutionKind;
+    use crate::program::branch_and_bound::BranchAndBound;
+
+    #[test]
+    fn reduction_works() {
+        let instance = Instance::from((
+            vec![(1.0, 1.0); 5],
+            3.0,
+            vec![(0, 1), (1, 2), (0, 2), (2, 3), (3, 4)],
+        ));
+        let program = instance.reduce_instance();
+        assert_eq!(5, program.number_of_variables());
+        assert_eq!(4, program.number_of_constraints());
+    }
+
+    #[test]
+    fn solving_by_reduction_works() {
+        let instance = Instance::from((
+            vec![(5.0, 3.0), (4.0, 2.0), (3.0, 2.0), (2.0, 1.0)],
+            5.0,
+            vec![(0, 1), (1, 3)],
+        ));
+        let solution = instance.solve_by_reduction(&BranchAndBound::default());
+        assert_eq!(Some(vec![0, 2]), solution.clone().as_solution());
+        assert_eq!(Some(8.0), solution.cost(&instance));
+    }
+}
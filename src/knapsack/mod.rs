@@ -4,7 +4,15 @@ use std::fmt::Display;
 use std::marker::PhantomData;
 
 pub mod algorithms;
+pub mod bounded;
+pub mod conflict;
+pub mod multidimensional;
+pub mod multiple;
+pub mod multiple_choice;
+pub mod preprocessing;
+pub mod quadratic;
 pub mod reductions;
+pub mod unbounded;
 
 #[derive(Clone, Debug)]
 pub struct Instance<I, C, W>
@@ -0,0 +1,191 @@
+use crate::algorithm::Algorithm;
+use crate::knapsack::multidimensional::{Instance, Item};
+use crate::knapsack::Solution;
+use crate::primitives::Numeric;
+use crate::program::model::Variable;
+use crate::program::{LpSolution, LpSolver};
+use crate::reduction::Reduction;
+
+/// Cost of an item divided by its weights relative to the capacities.
+fn efficiency<I, C, W>(instance: &Instance<I, C, W>, item: usize) -> f64
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    let item = &instance.items()[item];
+    let size: f64 = item
+        .weights()
+        .iter()
+        .zip(instance.capacities().iter())
+        .map(|(&weight, &capacity)| weight.into() / capacity.into())
+        .sum();
+    (*item.cost()).into() / size
+}
+
+/// Solves the LP relaxation with the built-in simplex method and packs the items by
+/// decreasing LP value, breaking ties by efficiency, as long as they fit. A basic
+/// solution has at most one fractional item per dimension, so only few items are
+/// decided by rounding.
+pub struct LpRounding;
+
+impl<I, C, W> Algorithm<Instance<I, C, W>> for LpRounding
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn run(&self, instance: &Instance<I, C, W>) -> Solution {
+        let relaxation = LpSolver::Simplex.run(&instance.reduce_instance());
+        if let LpSolution::Infeasible | LpSolution::Unbounded | LpSolution::Failed(_) = relaxation {
+            return instance.reduce_solution(&relaxation);
+        }
+        let mut order: Vec<(usize, f64, f64)> = (0..instance.number_of_items())
+            .map(|index| {
+                let value = relaxation.value(Variable::new(index)).unwrap_or(0.0);
+                (index, value, efficiency(instance, index))
+            })
+            .filter(|&(index, _, _)| *instance.items()[index].cost() > C::zero())
+            .collect();
+        order.sort_by(|a, b| {
+            (b.1, b.2)
+                .partial_cmp(&(a.1, a.2))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let mut usage = vec![W::zero(); instance.dimensions()];
+        let mut packed = Vec::new();
+        for (index, _, _) in order {
+            if instance.fits(&usage, index) {
+                instance.pack(&mut usage, index);
+                packed.push(index);
+            }
+        }
+        packed.sort_unstable();
+        Solution::Solved {
+            packed_items: packed,
+        }
+    }
+}
+
+/// Primal-dual greedy: keeps a price per dimension, repeatedly packs the fitting item
+/// with the best ratio of cost to priced weight and raises the price of every dimension
+/// exponentially in the fraction of its capacity the item consumes.
+pub struct PrimalDual;
+
+impl<I, C, W> Algorithm<Instance<I, C, W>> for PrimalDual
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn run(&self, instance: &Instance<I, C, W>) -> Solution {
+        let base = instance.dimensions() as f64 + 1.0;
+        let mut prices: Vec<f64> = instance
+            .capacities()
+            .iter()
+            .map(|&capacity| 1.0 / capacity.into().max(f64::MIN_POSITIVE))
+            .collect();
+        let mut usage = vec![W::zero(); instance.dimensions()];
+        let mut open: Vec<usize> = (0..instance.number_of_items())
+            .filter(|&index| *instance.items()[index].cost() > C::zero())
+            .collect();
+        let mut packed = Vec::new();
+
+        loop {
+            open.retain(|&index| instance.fits(&usage, index));
+            let ratio = |index: usize| {
+                let item = &instance.items()[index];
+                let priced: f64 = item
+                    .weights()
+                    .iter()
+                    .zip(prices.iter())
+                    .map(|(&weight, price)| weight.into() * price)
+                    .sum();
+                (*item.cost()).into() / priced
+            };
+            let best = open
+                .iter()
+                .enumerate()
+                .max_by(|(_, &a), (_, &b)| ratio(a).partial_cmp(&ratio(b)).unwrap());
+            let (position, index) = match best {
+                Some((position, &index)) => (position, index),
+                None => break,
+            };
+            open.swap_remove(position);
+            instance.pack(&mut usage, index);
+            packed.push(index);
+
+            let item = &instance.items()[index];
+            for ((price, &weight), &capacity) in prices
+                .iter_mut()
+                .zip(item.weights().iter())
+                .zip(instance.capacities().iter())
+            {
+                *price *= base.powf(weight.into() / capacity.into().max(f64::MIN_POSITIVE));
+            }
+        }
+        packed.sort_unstable();
+        Solution::Solved {
+            packed_items: packed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_super {
+    use super::*;
+    use crate::knapsack::multidimensional::DefaultItem;
+    use crate::problem::{OptProblemKind, SolutionKind};
+    use crate::program::branch_and_bound::BranchAndBound;
+    use rand::distributions::Uniform;
+    use rand::{thread_rng, Rng};
+
+    fn instance() -> Instance<DefaultItem<u32>, u32, u32> {
+        Instance::from((
+            vec![(3, vec![2, 1]), (4, vec![1, 3]), (1, vec![2, 2])],
+            vec![3, 4],
+        ))
+    }
+
+    fn random_validation(algorithm: impl Algorithm<Instance<DefaultItem<u32>, u32, u32>>) {
+        let mut rng = thread_rng();
+        let items: Vec<(u32, Vec<u32>)> = (0..20)
+            .map(|_| {
+                let weights = rng.sample_iter(Uniform::new(0, 50)).take(3).collect();
+                (rng.sample(Uniform::new(1, 100)), weights)
+            })
+            .collect();
+        let capacities = rng.sample_iter(Uniform::new(100, 300)).take(3).collect();
+        let instance = Instance::from((items, capacities));
+        let solution = algorithm.run(&instance);
+        let packed = solution.clone().as_solution().unwrap();
+        assert!(instance.is_feasible(&packed));
+        let optimal = instance.solve_by_reduction(&BranchAndBound::default());
+        assert!(solution.cost(&instance) <= optimal.cost(&instance));
+    }
+
+    #[test]
+    fn solving_by_lp_rounding_works() {
+        let instance = instance();
+        let solution = instance.run(LpRounding);
+        assert_eq!(Some(vec![0, 1]), solution.as_solution());
+    }
+
+    #[test]
+    fn random_validation_lp_rounding_alg() {
+        random_validation(LpRounding);
+    }
+
+    #[test]
+    fn solving_by_primal_dual_works() {
+        let instance = instance();
+        let solution = instance.run(PrimalDual);
+        assert_eq!(Some(vec![0, 1]), solution.as_solution());
+    }
+
+    #[test]
+    fn random_validation_primal_dual_alg() {
+        random_validation(PrimalDual);
+    }
+}
@@ -0,0 +1,228 @@
+use crate::knapsack::Solution;
+use crate::primitives::Numeric;
+use crate::problem::{OptProblemKind, SolutionKind};
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+pub mod algorithms;
+pub mod reductions;
+
+/// Knapsack instance with one capacity per dimension, e.g. weight, volume and budget.
+#[derive(Clone, Debug)]
+pub struct Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    items: Vec<I>,
+    capacities: Vec<W>,
+    cost_type: PhantomData<C>,
+}
+
+impl<I, C, W> Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    pub fn new(items: Vec<I>, capacities: Vec<W>) -> Self {
+        if items
+            .iter()
+            .any(|item| item.weights().len() != capacities.len())
+        {
+            panic!("Every item needs one weight per dimension!");
+        }
+        if items
+            .iter()
+            .any(|item| item.weights().iter().any(|weight| *weight < W::zero()))
+        {
+            panic!("Item weights must be non-negative!");
+        }
+        Instance {
+            items,
+            capacities,
+            cost_type: PhantomData,
+        }
+    }
+
+    pub fn items(&self) -> &[I] {
+        &self.items
+    }
+
+    pub fn number_of_items(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn capacities(&self) -> &[W] {
+        &self.capacities
+    }
+
+    pub fn dimensions(&self) -> usize {
+        self.capacities.len()
+    }
+
+    /// Whether `item` can be added to a packing that uses `usage` of every dimension.
+    pub fn fits(&self, usage: &[W], item: usize) -> bool {
+        self.items[item]
+            .weights()
+            .iter()
+            .zip(usage.iter().zip(self.capacities.iter()))
+            .all(|(&weight, (&used, &capacity))| used + weight <= capacity)
+    }
+
+    pub fn is_feasible(&self, packed_items: &[usize]) -> bool {
+        let mut usage = vec![W::zero(); self.dimensions()];
+        for &item in packed_items {
+            if !self.fits(&usage, item) {
+                return false;
+            }
+            self.pack(&mut usage, item);
+        }
+        true
+    }
+
+    fn pack(&self, usage: &mut [W], item: usize) {
+        for (used, &weight) in usage.iter_mut().zip(self.items[item].weights().iter()) {
+            *used += weight;
+        }
+    }
+}
+
+impl<I, C, W> Display for Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Multidimensional knapsack instance\n   - capacities = [{}]\n   - items = {{ {} }}",
+            self.capacities
+                .iter()
+                .map(|capacity| capacity.to_string())
+                .collect::<Vec<String>>()
+                .join(", "),
+            self.items
+                .iter()
+                .map(|item| item.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+impl<I, C, W> OptProblemKind for Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    type Solution = Solution;
+    type Cost = C;
+}
+
+impl<I, C, W> SolutionKind<Instance<I, C, W>> for Solution
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn cost(&self, instance: &Instance<I, C, W>) -> Option<C> {
+        match self {
+            Solution::Infeasible | Solution::Failed(_) => None,
+            Solution::Solved { packed_items } => Some(
+                packed_items
+                    .iter()
+                    .map(|&item| *instance.items()[item].cost())
+                    .sum(),
+            ),
+        }
+    }
+}
+
+/// Counterpart of [`knapsack::Item`](crate::knapsack::Item) with one weight per dimension.
+pub trait Item<C, W>: Display + Clone
+where
+    C: Numeric,
+    W: Numeric,
+{
+    fn weights(&self) -> &[W];
+    fn cost(&self) -> &C;
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct DefaultItem<T>
+where
+    T: Numeric,
+{
+    cost: T,
+    weights: Vec<T>,
+}
+
+impl<T> Display for DefaultItem<T>
+where
+    T: Numeric,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Item[c = {}, w = ({})]",
+            self.cost,
+            self.weights
+                .iter()
+                .map(|weight| weight.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+impl<T> Item<T, T> for DefaultItem<T>
+where
+    T: Numeric,
+{
+    fn weights(&self) -> &[T] {
+        &self.weights
+    }
+    fn cost(&self) -> &T {
+        &self.cost
+    }
+}
+
+impl<T> From<(T, Vec<T>)> for DefaultItem<T>
+where
+    T: Numeric,
+{
+    fn from(input: (T, Vec<T>)) -> Self {
+        DefaultItem {
+            cost: input.0,
+            weights: input.1,
+        }
+    }
+}
+
+impl<T> From<(Vec<(T, Vec<T>)>, Vec<T>)> for Instance<DefaultItem<T>, T, T>
+where
+    T: Numeric,
+{
+    fn from(input: (Vec<(T, Vec<T>)>, Vec<T>)) -> Self {
+        let items = input.0.into_iter().map(DefaultItem::from).collect();
+        Instance::new(items, input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_instance_from_works() {
+        let instance = Instance::from((vec![(3, vec![2, 1]), (4, vec![1, 3])], vec![3, 3]));
+        assert_eq!(2, instance.number_of_items());
+        assert_eq!(2, instance.dimensions());
+        assert!(instance.is_feasible(&[0]));
+        assert!(!instance.is_feasible(&[0, 1]));
+    }
+}
@@ -0,0 +1,97 @@
+use crate::knapsack::multidimensional::{Instance, Item};
+use crate::knapsack::reductions::{decode_solution, packed_items};
+use crate::knapsack::Solution;
+use crate::primitives::Numeric;
+use crate::program::model::{LinearExpr, Sense, Variable};
+use crate::program::{LpSolution, MathProgram};
+use crate::reduction::Reduction;
+
+impl<I, C, W> Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    pub(super) fn item_variables(&self, program: &mut MathProgram) -> Vec<Variable> {
+        (0..self.items.len())
+            .map(|index| program.add_binary(&format!("x_{}", index)))
+            .collect()
+    }
+}
+
+impl<I, C, W> Reduction<MathProgram> for Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn reduce_instance(&self) -> MathProgram {
+        let mut model = MathProgram::new("multidimensional_knapsack", Sense::Maximize);
+        let vars = self.item_variables(&mut model);
+
+        model.set_objective(
+            self.items
+                .iter()
+                .zip(vars.iter())
+                .map(|(item, var)| (*item.cost()).into() * *var)
+                .sum::<LinearExpr>(),
+        );
+        for (dimension, capacity) in self.capacities.iter().enumerate() {
+            model.add_constraint(
+                self.items
+                    .iter()
+                    .zip(vars.iter())
+                    .map(|(item, var)| item.weights()[dimension].into() * *var)
+                    .sum::<LinearExpr>()
+                    .le((*capacity).into()),
+            );
+        }
+
+        model
+    }
+
+    fn reduce_solution(&self, solution: &LpSolution) -> Solution {
+        decode_solution(
+            solution,
+            "multidimensional knapsack",
+            self.items.len(),
+            |values| Solution::Solved {
+                packed_items: packed_items(&values),
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod test_super {
+    use super::*;
+    use crate::problem::SolutionKind;
+    use crate::program::branch_and_bound::BranchAndBound;
+
+    #[test]
+    fn reduction_works() {
+        let instance = Instance::from((
+            vec![(3.0, vec![2.0, 1.0]), (4.0, vec![1.0, 3.0])],
+            vec![3.0, 3.0],
+        ));
+        let program = instance.reduce_instance();
+        assert_eq!(2, program.number_of_variables());
+        assert_eq!(2, program.number_of_constraints());
+    }
+
+    #[test]
+    fn solving_by_reduction_works() {
+        let instance = Instance::from((
+            vec![
+                (3.0, vec![2.0, 1.0]),
+                (4.0, vec![1.0, 3.0]),
+                (2.0, vec![1.0, 1.0]),
+                (3.0, vec![2.0, 2.0]),
+            ],
+            vec![4.0, 4.0],
+        ));
+        let solution = instance.solve_by_reduction(&BranchAndBound::default());
+        assert_eq!(Some(vec![0, 1]), solution.clone().as_solution());
+        assert_eq!(Some(7.0), solution.cost(&instance));
+    }
+}
@@ -0,0 +1,277 @@
+use crate::algorithm::*;
+use crate::knapsack::algorithms::Greedy as SingleGreedy;
+use crate::knapsack::multiple::{Instance, Solution};
+use crate::knapsack::{self, Item};
+use crate::primitives::Numeric;
+use crate::problem::SolutionKind;
+use crate::program::branch_and_bound::BranchAndBound;
+use crate::reduction::Reduction;
+
+/// Fills the bags one after another with the single knapsack greedy on the remaining items.
+pub struct Greedy;
+
+impl<I, C, W> Algorithm<Instance<I, C, W>> for Greedy
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn run(&self, instance: &Instance<I, C, W>) -> Solution {
+        let mut assignment = vec![None; instance.number_of_items()];
+        for (bag, size) in instance.bag_sizes().iter().enumerate() {
+            let remaining: Vec<usize> = (0..instance.number_of_items())
+                .filter(|&index| {
+                    assignment[index].is_none() && instance.items()[index].weight() <= size
+                })
+                .collect();
+            if remaining.is_empty() {
+                continue;
+            }
+            let single = knapsack::Instance::new(
+                remaining
+                    .iter()
+                    .map(|&index| instance.items()[index].clone())
+                    .collect(),
+                *size,
+            );
+            match SingleGreedy.run(&single) {
+                knapsack::Solution::Solved { packed_items } => {
+                    for item in packed_items {
+                        assignment[remaining[item]] = Some(bag);
+                    }
+                }
+                knapsack::Solution::Infeasible => return Solution::Infeasible,
+                knapsack::Solution::Failed(msg) => return Solution::Failed(msg),
+            }
+        }
+        Solution::Solved { assignment }
+    }
+}
+
+/// Exact bound-and-bound in the style of Martello and Toth.
+///
+/// Every node is bounded from above by the surrogate relaxation, which merges
+/// all residual capacities into a single bag, and from below by filling the bags
+/// greedily. Subtrees where both bounds meet are not explored further.
+pub struct BoundAndBound;
+
+struct Search<'a, I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    instance: &'a Instance<I, C, W>,
+    /// Item indices by non-increasing efficiency.
+    order: Vec<usize>,
+    loads: Vec<W>,
+    assignment: Vec<Option<usize>>,
+    best: Vec<Option<usize>>,
+    best_cost: f64,
+}
+
+impl<'a, I, C, W> Search<'a, I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn residual(&self, bag: usize) -> f64 {
+        self.instance.bag_sizes()[bag].into() - self.loads[bag].into()
+    }
+
+    fn fits(&self, bag: usize, item: usize) -> bool {
+        self.loads[bag] + *self.instance.items()[item].weight() <= self.instance.bag_sizes()[bag]
+    }
+
+    /// Dantzig bound of the remaining items for the merged residual capacity.
+    fn upper_bound(&self, depth: usize) -> f64 {
+        let mut capacity: f64 = (0..self.loads.len()).map(|bag| self.residual(bag)).sum();
+        let mut bound = 0.0;
+        for &index in &self.order[depth..] {
+            let item = &self.instance.items()[index];
+            let weight: f64 = (*item.weight()).into();
+            let cost: f64 = (*item.cost()).into();
+            if weight <= capacity {
+                capacity -= weight;
+                bound += cost;
+            } else {
+                bound += cost * capacity / weight;
+                break;
+            }
+        }
+        bound
+    }
+
+    /// Completes the current assignment by first fit and records it if it improves.
+    fn lower_bound(&mut self, depth: usize, value: f64) -> f64 {
+        let mut loads = self.loads.clone();
+        let mut assignment = self.assignment.clone();
+        let mut bound = value;
+        for &index in &self.order[depth..] {
+            let item = &self.instance.items()[index];
+            if let Some(bag) = (0..loads.len())
+                .find(|&bag| loads[bag] + *item.weight() <= self.instance.bag_sizes()[bag])
+            {
+                loads[bag] += *item.weight();
+                assignment[index] = Some(bag);
+                bound += (*item.cost()).into();
+            }
+        }
+        if bound > self.best_cost {
+            self.best_cost = bound;
+            self.best = assignment;
+        }
+        bound
+    }
+
+    fn explore(&mut self, depth: usize, value: f64) {
+        let upper = value + self.upper_bound(depth);
+        if upper <= self.best_cost {
+            return;
+        }
+        let lower = self.lower_bound(depth, value);
+        if depth == self.order.len() || lower >= upper {
+            return;
+        }
+
+        let index = self.order[depth];
+        let cost: f64 = (*self.instance.items()[index].cost()).into();
+        let weight = *self.instance.items()[index].weight();
+        let mut tried: Vec<f64> = Vec::new();
+        for bag in 0..self.loads.len() {
+            // bags with equal residual capacity lead to symmetric subtrees
+            let residual = self.residual(bag);
+            if !self.fits(bag, index) || tried.contains(&residual) {
+                continue;
+            }
+            tried.push(residual);
+            let load = self.loads[bag];
+            self.loads[bag] += weight;
+            self.assignment[index] = Some(bag);
+            self.explore(depth + 1, value + cost);
+            self.assignment[index] = None;
+            self.loads[bag] = load;
+        }
+        self.explore(depth + 1, value);
+    }
+}
+
+impl<I, C, W> Algorithm<Instance<I, C, W>> for BoundAndBound
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn run(&self, instance: &Instance<I, C, W>) -> Solution {
+        let largest = instance
+            .bag_sizes()
+            .iter()
+            .fold(None, |max: Option<W>, size| match max {
+                Some(max) if max >= *size => Some(max),
+                _ => Some(*size),
+            });
+        let mut order: Vec<usize> = (0..instance.number_of_items())
+            .filter(|&index| Some(*instance.items()[index].weight()) <= largest)
+            .collect();
+        order.sort_by(|&a, &b| {
+            let ratio = |index: usize| {
+                let item = &instance.items()[index];
+                (*item.cost()).into() / (*item.weight()).into()
+            };
+            ratio(b).partial_cmp(&ratio(a)).unwrap()
+        });
+
+        let mut search = Search {
+            instance,
+            order,
+            loads: vec![W::zero(); instance.number_of_bags()],
+            assignment: vec![None; instance.number_of_items()],
+            best: vec![None; instance.number_of_items()],
+            best_cost: f64::NEG_INFINITY,
+        };
+        search.explore(0, 0.0);
+        Solution::Solved {
+            assignment: search.best,
+        }
+    }
+}
+
+impl<I, C, W> TheoreticValidation<Instance<I, C, W>> for BoundAndBound
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn validate(&self, instance: &Instance<I, C, W>, solution: &Solution) -> TheoreticGuarantee {
+        let optimal_solution = instance.solve_by_reduction(&BranchAndBound::default());
+        match (solution.cost(instance), optimal_solution.cost(instance)) {
+            (Some(alg), Some(opt)) => {
+                if (alg.into() - opt.into()).abs() > 1e-6 {
+                    TheoreticGuarantee::Inconsistent(format!(
+                        "Bound-and-bound did not find an optimal solution: {} != {}",
+                        alg, opt
+                    ))
+                } else {
+                    TheoreticGuarantee::Consistent
+                }
+            }
+            _ => TheoreticGuarantee::Failed(String::from(
+                "Error: Cost of optimal solution and algorithm could not have been computed!",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_super {
+    use super::*;
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn greedy_works() {
+        let instance = Instance::from((
+            vec![(3.0, 2.0), (4.0, 3.0), (5.0, 4.0), (2.0, 2.0)],
+            vec![5.0, 4.0],
+        ));
+        let solution = Greedy.run(&instance);
+        assert!(instance.is_feasible(&solution.clone().as_solution().unwrap()));
+        assert_eq!(Some(12.0), solution.cost(&instance));
+    }
+
+    #[test]
+    fn random_validation_greedy_alg() {
+        let mut rng = thread_rng();
+        let items: Vec<(u32, u32)> = (0..30)
+            .map(|_| (rng.gen_range(1, 20), rng.gen_range(1, 12)))
+            .collect();
+        let bags: Vec<u32> = (0..4).map(|_| rng.gen_range(1, 40)).collect();
+        let instance = Instance::from((items, bags));
+        let assignment = Greedy.run(&instance).as_solution().unwrap();
+        assert!(instance.is_feasible(&assignment));
+    }
+
+    #[test]
+    fn bound_and_bound_works() {
+        let instance = Instance::from((
+            vec![(3.0, 2.0), (4.0, 3.0), (5.0, 4.0), (2.0, 2.0)],
+            vec![5.0, 4.0],
+        ));
+        let solution = BoundAndBound.run(&instance);
+        assert!(instance.is_feasible(&solution.clone().as_solution().unwrap()));
+        assert_eq!(Some(12.0), solution.cost(&instance));
+    }
+
+    #[test]
+    fn random_validation_bound_and_bound_alg() {
+        let mut rng = thread_rng();
+        let items: Vec<(u32, u32)> = (0..10)
+            .map(|_| (rng.gen_range(1, 20), rng.gen_range(1, 12)))
+            .collect();
+        let bags: Vec<u32> = (0..3).map(|_| rng.gen_range(1, 20)).collect();
+        let instance = Instance::from((items, bags));
+        let solution = BoundAndBound.run(&instance);
+        assert!(instance.is_feasible(solution.clone().as_solution().as_ref().unwrap()));
+        assert!(BoundAndBound.validate(&instance, &solution).is_correct());
+    }
+}
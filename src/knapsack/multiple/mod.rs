@@ -0,0 +1,179 @@
+use crate::knapsack::{DefaultItem, Item};
+use crate::primitives::Numeric;
+use crate::problem::{OptProblemKind, SolutionKind};
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+pub mod algorithms;
+pub mod reductions;
+
+/// Knapsack instance with several bags of individual capacities.
+#[derive(Clone, Debug)]
+pub struct Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    items: Vec<I>,
+    bag_sizes: Vec<W>,
+    cost_type: PhantomData<C>,
+}
+
+impl<I, C, W> Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    pub fn new(items: Vec<I>, bag_sizes: Vec<W>) -> Self {
+        if items.iter().any(|item| *item.weight() <= W::zero()) {
+            panic!("Item weights must be positive!");
+        }
+        Instance {
+            items,
+            bag_sizes,
+            cost_type: PhantomData,
+        }
+    }
+
+    pub fn items(&self) -> &[I] {
+        &self.items
+    }
+
+    pub fn number_of_items(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn bag_sizes(&self) -> &[W] {
+        &self.bag_sizes
+    }
+
+    pub fn number_of_bags(&self) -> usize {
+        self.bag_sizes.len()
+    }
+
+    /// Whether every bag of `assignment` holds at most its size.
+    pub fn is_feasible(&self, assignment: &[Option<usize>]) -> bool {
+        let mut loads = vec![W::zero(); self.number_of_bags()];
+        for (item, bag) in self.items.iter().zip(assignment.iter()) {
+            if let Some(bag) = *bag {
+                match loads.get_mut(bag) {
+                    Some(load) => *load += *item.weight(),
+                    None => return false,
+                }
+            }
+        }
+        loads
+            .iter()
+            .zip(self.bag_sizes.iter())
+            .all(|(load, size)| load <= size)
+    }
+}
+
+impl<I, C, W> Display for Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Multiple knapsack instance\n   - sizes = [{}]\n   - items = {{ {} }}",
+            self.bag_sizes
+                .iter()
+                .map(|size| size.to_string())
+                .collect::<Vec<String>>()
+                .join(", "),
+            self.items
+                .iter()
+                .map(|item| item.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+impl<I, C, W> OptProblemKind for Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    type Solution = Solution;
+    type Cost = C;
+}
+
+#[derive(Clone, Debug)]
+pub enum Solution {
+    /// `assignment[i]` is the bag of item `i`, or `None` if it is left out.
+    Solved {
+        assignment: Vec<Option<usize>>,
+    },
+    Infeasible,
+    Failed(String),
+}
+
+impl Solution {
+    pub fn is_solved(&self) -> bool {
+        matches!(self, Solution::Solved { .. })
+    }
+
+    pub fn as_solution(self) -> Option<Vec<Option<usize>>> {
+        match self {
+            Solution::Solved { assignment } => Some(assignment),
+            _ => None,
+        }
+    }
+}
+
+impl<I, C, W> SolutionKind<Instance<I, C, W>> for Solution
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn cost(&self, instance: &Instance<I, C, W>) -> Option<C> {
+        match self {
+            Solution::Infeasible | Solution::Failed(_) => None,
+            Solution::Solved { assignment } => Some(
+                instance
+                    .items()
+                    .iter()
+                    .zip(assignment.iter())
+                    .filter(|(_, bag)| bag.is_some())
+                    .map(|(item, _)| *item.cost())
+                    .sum(),
+            ),
+        }
+    }
+}
+
+impl<T> From<(Vec<(T, T)>, Vec<T>)> for Instance<DefaultItem<T>, T, T>
+where
+    T: Numeric,
+{
+    fn from(input: (Vec<(T, T)>, Vec<T>)) -> Self {
+        let items = input.0.into_iter().map(DefaultItem::from).collect();
+        Instance::new(items, input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_instance_from_works() {
+        let instance = Instance::from((vec![(1, 2), (2, 3), (3, 4)], vec![5, 4]));
+        assert_eq!(3, instance.number_of_items());
+        assert_eq!(2, instance.number_of_bags());
+        let solution = Solution::Solved {
+            assignment: vec![Some(0), Some(0), Some(1)],
+        };
+        assert!(instance.is_feasible(&[Some(0), Some(0), Some(1)]));
+        assert!(!instance.is_feasible(&[Some(1), Some(0), Some(0)]));
+        assert_eq!(Some(6), solution.cost(&instance));
+    }
+}
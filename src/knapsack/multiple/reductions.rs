@@ -0,0 +1,116 @@
+use crate::knapsack::multiple::{Instance, Solution};
+use crate::knapsack::reductions::{decode_solution, IlpSolution};
+use crate::knapsack::Item;
+use crate::primitives::Numeric;
+use crate::program::model::{LinearExpr, Sense, Variable};
+use crate::program::{LpSolution, MathProgram};
+use crate::reduction::Reduction;
+
+impl<I, C, W> Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    /// Binary variables `x_{i,b}`, indexed as `vars[i][b]`.
+    fn assignment_variables(&self, program: &mut MathProgram) -> Vec<Vec<Variable>> {
+        (0..self.items.len())
+            .map(|item| {
+                (0..self.bag_sizes.len())
+                    .map(|bag| program.add_binary(&format!("x_{}_{}", item, bag)))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl<I, C, W> Reduction<MathProgram> for Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn reduce_instance(&self) -> MathProgram {
+        let mut model = MathProgram::new("multiple_knapsack", Sense::Maximize);
+        let vars = self.assignment_variables(&mut model);
+
+        model.set_objective(
+            self.items
+                .iter()
+                .zip(vars.iter())
+                .flat_map(|(item, bags)| bags.iter().map(move |var| (*item.cost()).into() * *var))
+                .sum::<LinearExpr>(),
+        );
+        for bags in vars.iter() {
+            model.add_constraint(
+                bags.iter()
+                    .map(|var| LinearExpr::from(*var))
+                    .sum::<LinearExpr>()
+                    .le(1.0),
+            );
+        }
+        for (bag, size) in self.bag_sizes.iter().enumerate() {
+            model.add_constraint(
+                self.items
+                    .iter()
+                    .zip(vars.iter())
+                    .map(|(item, bags)| (*item.weight()).into() * bags[bag])
+                    .sum::<LinearExpr>()
+                    .le((*size).into()),
+            );
+        }
+
+        model
+    }
+
+    fn reduce_solution(&self, solution: &LpSolution) -> Solution {
+        let bags = self.bag_sizes.len();
+        decode_solution(
+            solution,
+            "multiple knapsack",
+            self.items.len() * bags,
+            |values| Solution::Solved {
+                assignment: (0..self.items.len())
+                    .map(|item| (0..bags).rposition(|bag| values[item * bags + bag] > 0.0))
+                    .collect(),
+            },
+        )
+    }
+}
+
+impl IlpSolution for Solution {
+    fn infeasible() -> Self {
+        Solution::Infeasible
+    }
+
+    fn failed(message: String) -> Self {
+        Solution::Failed(message)
+    }
+}
+
+#[cfg(test)]
+mod test_super {
+    use super::*;
+    use crate::problem::SolutionKind;
+    use crate::program::branch_and_bound::BranchAndBound;
+
+    #[test]
+    fn reduction_works() {
+        let instance = Instance::from((vec![(3.0, 2.0), (4.0, 3.0), (2.0, 1.0)], vec![3.0, 4.0]));
+        let program = instance.reduce_instance();
+        assert_eq!(6, program.number_of_variables());
+        assert_eq!(5, program.number_of_constraints());
+    }
+
+    #[test]
+    fn solving_by_reduction_works() {
+        let instance = Instance::from((
+            vec![(3.0, 2.0), (4.0, 3.0), (5.0, 4.0), (2.0, 2.0)],
+            vec![5.0, 4.0],
+        ));
+        let solution = instance.solve_by_reduction(&BranchAndBound::default());
+        let assignment = solution.clone().as_solution().unwrap();
+        assert!(instance.is_feasible(&assignment));
+        assert_eq!(Some(12.0), solution.cost(&instance));
+    }
+}
@@ -0,0 +1,398 @@
+use crate::algorithm::*;
+use crate::knapsack::multiple_choice::{Instance, Solution};
+use crate::knapsack::Item;
+use crate::primitives::Numeric;
+use crate::problem::SolutionKind;
+use crate::program::branch_and_bound::BranchAndBound;
+use crate::reduction::Reduction;
+
+/// Optimal solution of the LP relaxation. Every class takes its item of `choices`,
+/// except for at most one class that is split between two items.
+#[derive(Clone, Debug)]
+pub struct Relaxation {
+    choices: Vec<usize>,
+    split: Option<(usize, usize, f64)>,
+    value: f64,
+}
+
+impl Relaxation {
+    /// Items taken as a whole, the split class lists its lighter item.
+    pub fn choices(&self) -> &[usize] {
+        &self.choices
+    }
+
+    /// The split class, its heavier item and the fraction taken of it.
+    pub fn split(&self) -> Option<(usize, usize, f64)> {
+        self.split
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+/// Solves the LP relaxation in the style of Dyer and Zemel: items of every class are
+/// paired, the median slope of the pairs is tested as Lagrangian multiplier of the
+/// capacity and at least half of the pairs lose one of their items in every round.
+/// As an algorithm it drops the fractional part of the relaxation.
+pub struct DyerZemel;
+
+impl DyerZemel {
+    /// Returns `None` if even the lightest items exceed the bag.
+    pub fn relax<I, C, W>(&self, instance: &Instance<I, C, W>) -> Option<Relaxation>
+    where
+        I: Item<C, W>,
+        C: Numeric,
+        W: Numeric,
+    {
+        let weight = |class: usize, item: usize| -> f64 {
+            (*instance.classes()[class][item].weight()).into()
+        };
+        let cost =
+            |class: usize, item: usize| -> f64 { (*instance.classes()[class][item].cost()).into() };
+        let size: f64 = (*instance.bag_size()).into();
+
+        let mut candidates: Vec<Vec<usize>> = instance
+            .classes()
+            .iter()
+            .map(|class| (0..class.len()).collect())
+            .collect();
+        let lightest: f64 = candidates
+            .iter()
+            .enumerate()
+            .map(|(class, items)| {
+                items
+                    .iter()
+                    .map(|&item| weight(class, item))
+                    .fold(f64::INFINITY, f64::min)
+            })
+            .sum();
+        if lightest > size {
+            return None;
+        }
+
+        while candidates.iter().any(|items| items.len() > 1) {
+            // (class, lighter, heavier, slope) of every pair without dominance
+            let mut pairs: Vec<(usize, usize, usize, f64)> = Vec::new();
+            for (class, items) in candidates.iter_mut().enumerate() {
+                let mut kept = Vec::with_capacity(items.len());
+                for pair in items.chunks(2) {
+                    if pair.len() == 1 {
+                        kept.push(pair[0]);
+                        continue;
+                    }
+                    let (light, heavy) = if weight(class, pair[0]) <= weight(class, pair[1]) {
+                        (pair[0], pair[1])
+                    } else {
+                        (pair[1], pair[0])
+                    };
+                    let (dw, dc) = (
+                        weight(class, heavy) - weight(class, light),
+                        cost(class, heavy) - cost(class, light),
+                    );
+                    if dc <= 0.0 {
+                        kept.push(light);
+                    } else if dw <= 0.0 {
+                        kept.push(heavy);
+                    } else {
+                        kept.push(light);
+                        kept.push(heavy);
+                        pairs.push((class, light, heavy, dc / dw));
+                    }
+                }
+                *items = kept;
+            }
+            if pairs.is_empty() {
+                continue;
+            }
+
+            let median = pairs.len() / 2;
+            pairs.select_nth_unstable_by(median, |a, b| a.3.partial_cmp(&b.3).unwrap());
+            let slope = pairs[median].3;
+
+            // lightest and heaviest maximizer of `c - slope * w` per class
+            let extremes: Vec<(usize, usize)> = candidates
+                .iter()
+                .enumerate()
+                .map(|(class, items)| {
+                    let reduced = |item: usize| cost(class, item) - slope * weight(class, item);
+                    let best = items
+                        .iter()
+                        .map(|&item| reduced(item))
+                        .fold(f64::NEG_INFINITY, f64::max);
+                    let tolerance = 1e-9 * (1.0 + best.abs());
+                    let maximizers = items
+                        .iter()
+                        .copied()
+                        .filter(|&item| reduced(item) >= best - tolerance);
+                    let light = maximizers
+                        .clone()
+                        .min_by(|&a, &b| weight(class, a).partial_cmp(&weight(class, b)).unwrap())
+                        .unwrap();
+                    let heavy = maximizers
+                        .max_by(|&a, &b| weight(class, a).partial_cmp(&weight(class, b)).unwrap())
+                        .unwrap();
+                    (light, heavy)
+                })
+                .collect();
+            let light_weight: f64 = extremes
+                .iter()
+                .enumerate()
+                .map(|(class, &(light, _))| weight(class, light))
+                .sum();
+            let heavy_weight: f64 = extremes
+                .iter()
+                .enumerate()
+                .map(|(class, &(_, heavy))| weight(class, heavy))
+                .sum();
+
+            if light_weight <= size && size <= heavy_weight {
+                let mut choices: Vec<usize> = extremes.iter().map(|&(light, _)| light).collect();
+                let mut residual = size - light_weight;
+                let mut split = None;
+                for (class, &(light, heavy)) in extremes.iter().enumerate() {
+                    let dw = weight(class, heavy) - weight(class, light);
+                    if dw <= 0.0 {
+                        continue;
+                    } else if dw <= residual {
+                        choices[class] = heavy;
+                        residual -= dw;
+                    } else {
+                        split = Some((class, heavy, residual / dw));
+                        break;
+                    }
+                }
+                let value = choices
+                    .iter()
+                    .enumerate()
+                    .map(|(class, &item)| cost(class, item))
+                    .sum::<f64>()
+                    + split.map_or(0.0, |(class, heavy, fraction)| {
+                        fraction * (cost(class, heavy) - cost(class, choices[class]))
+                    });
+                return Some(Relaxation {
+                    choices,
+                    split,
+                    value,
+                });
+            }
+
+            for &(class, light, heavy, pair_slope) in &pairs {
+                let dropped = if light_weight > size && pair_slope <= slope {
+                    heavy
+                } else if heavy_weight < size && pair_slope >= slope {
+                    light
+                } else {
+                    continue;
+                };
+                candidates[class].retain(|&item| item != dropped);
+            }
+        }
+
+        let choices: Vec<usize> = candidates.iter().map(|items| items[0]).collect();
+        let value = choices
+            .iter()
+            .enumerate()
+            .map(|(class, &item)| cost(class, item))
+            .sum();
+        Some(Relaxation {
+            choices,
+            split: None,
+            value,
+        })
+    }
+}
+
+impl<I, C, W> Algorithm<Instance<I, C, W>> for DyerZemel
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn run(&self, instance: &Instance<I, C, W>) -> Solution {
+        match self.relax(instance) {
+            Some(relaxation) => Solution::Solved {
+                choices: relaxation.choices,
+            },
+            None => Solution::Infeasible,
+        }
+    }
+}
+
+impl<I, C, W> TheoreticValidation<Instance<I, C, W>> for DyerZemel
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn validate(&self, instance: &Instance<I, C, W>, solution: &Solution) -> TheoreticGuarantee {
+        let optimal_solution = instance.solve_by_reduction(&BranchAndBound::default());
+        match (
+            solution.cost(instance),
+            optimal_solution.cost(instance),
+            self.relax(instance),
+        ) {
+            (Some(alg), Some(opt), Some(relaxation)) => {
+                let (alg, opt) = (alg.into(), opt.into());
+                if alg > opt + 1e-6 || opt > relaxation.value() + 1e-6 {
+                    TheoreticGuarantee::Inconsistent(format!(
+                        "Rounded and relaxed value do not enclose the optimum: {} <= {} <= {} does not hold",
+                        alg,
+                        opt,
+                        relaxation.value()
+                    ))
+                } else {
+                    TheoreticGuarantee::Consistent
+                }
+            }
+            _ => TheoreticGuarantee::Failed(String::from(
+                "Error: Cost of optimal solution and algorithm could not have been computed!",
+            )),
+        }
+    }
+}
+
+/// Dynamic program over the bag size that chooses one item per class, i.e. it takes
+/// `O(W * n)` time for `n` items in total.
+pub struct SimpleDP;
+
+impl<I, C> Algorithm<Instance<I, C, u32>> for SimpleDP
+where
+    I: Item<C, u32>,
+    C: Numeric,
+{
+    fn run(&self, instance: &Instance<I, C, u32>) -> Solution {
+        let size = *instance.bag_size() as usize;
+        let mut values: Vec<Option<C>> = vec![Some(C::zero()); size + 1];
+        let mut decisions: Vec<Vec<usize>> = Vec::with_capacity(instance.number_of_classes());
+        for class in instance.classes() {
+            let mut next: Vec<Option<C>> = vec![None; size + 1];
+            let mut decision = vec![0; size + 1];
+            for j in 0..=size {
+                for (index, item) in class.iter().enumerate() {
+                    let weight = *item.weight() as usize;
+                    if weight > j {
+                        continue;
+                    }
+                    if let Some(value) = values[j - weight] {
+                        let value = value + *item.cost();
+                        let improves = match next[j] {
+                            Some(best) => value > best,
+                            None => true,
+                        };
+                        if improves {
+                            next[j] = Some(value);
+                            decision[j] = index;
+                        }
+                    }
+                }
+            }
+            values = next;
+            decisions.push(decision);
+        }
+
+        if values[size].is_none() {
+            return Solution::Infeasible;
+        }
+        let mut choices = vec![0; instance.number_of_classes()];
+        let mut left_weight = size;
+        for (class, decision) in decisions.iter().enumerate().rev() {
+            choices[class] = decision[left_weight];
+            left_weight -= *instance.classes()[class][choices[class]].weight() as usize;
+        }
+        Solution::Solved { choices }
+    }
+}
+
+#[cfg(test)]
+mod test_super {
+    use super::*;
+    use crate::program::{LpSolution, LpSolver};
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn simple_dp_works() {
+        let instance = Instance::from((
+            vec![
+                vec![(1, 2), (3, 4), (2, 5)],
+                vec![(0, 0), (1, 3), (4, 5)],
+                vec![(2, 1), (5, 6)],
+            ],
+            9,
+        ));
+        let solution = SimpleDP.run(&instance);
+        assert_eq!(Some(vec![0, 2, 0]), solution.clone().as_solution());
+        assert_eq!(Some(7), solution.cost(&instance));
+    }
+
+    #[test]
+    fn simple_dp_detects_infeasibility() {
+        let instance = Instance::from((vec![vec![(1, 4)], vec![(2, 3), (3, 5)]], 6));
+        assert!(matches!(SimpleDP.run(&instance), Solution::Infeasible));
+    }
+
+    #[test]
+    fn random_validation_simple_dp_alg() {
+        let mut rng = thread_rng();
+        let classes: Vec<Vec<(u32, u32)>> = (0..5)
+            .map(|_| {
+                (0..rng.gen_range(1, 5))
+                    .map(|_| (rng.gen_range(0, 20), rng.gen_range(0, 10)))
+                    .collect()
+            })
+            .collect();
+        let instance = Instance::from((classes, rng.gen_range(10, 40)));
+        let optimum = instance.solve_by_reduction(&BranchAndBound::default());
+        let solution = SimpleDP.run(&instance);
+        assert_eq!(optimum.cost(&instance), solution.cost(&instance));
+    }
+
+    #[test]
+    fn dyer_zemel_works() {
+        let instance = Instance::from((
+            vec![
+                vec![(1, 2), (3, 4), (2, 5)],
+                vec![(0, 0), (1, 3), (4, 5)],
+                vec![(2, 1), (5, 6)],
+            ],
+            9,
+        ));
+        let relaxation = DyerZemel.relax(&instance).unwrap();
+        assert_eq!(&[1, 0, 0], relaxation.choices());
+        let (class, item, fraction) = relaxation.split().unwrap();
+        assert_eq!((1, 2), (class, item));
+        assert!((fraction - 0.8).abs() < 1e-9);
+        assert!((relaxation.value() - 8.2).abs() < 1e-9);
+        assert_eq!(Some(5), DyerZemel.run(&instance).cost(&instance));
+    }
+
+    #[test]
+    fn random_validation_dyer_zemel_alg() {
+        let mut rng = thread_rng();
+        let classes: Vec<Vec<(u32, u32)>> = (0..20)
+            .map(|_| {
+                (0..rng.gen_range(1, 8))
+                    .map(|_| (rng.gen_range(0, 20), rng.gen_range(0, 10)))
+                    .collect()
+            })
+            .collect();
+        let instance = Instance::from((classes, rng.gen_range(40, 120)));
+        let relaxation = DyerZemel.relax(&instance);
+        match LpSolver::Simplex.run(&instance.reduce_instance()) {
+            LpSolution::Solved {
+                value: Some(value), ..
+            } => {
+                let relaxation = relaxation.unwrap();
+                assert!((relaxation.value() - value).abs() < 1e-6);
+                let solution = DyerZemel.run(&instance);
+                assert!(instance.is_feasible(solution.as_solution().as_ref().unwrap()));
+            }
+            LpSolution::Infeasible => assert!(relaxation.is_none()),
+            _ => panic!("Unexpected LP solution"),
+        }
+        let solution = DyerZemel.run(&instance);
+        if solution.is_solved() {
+            assert!(DyerZemel.validate(&instance, &solution).is_correct());
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use crate::knapsack::{DefaultItem, Item};
+use crate::primitives::Numeric;
+use crate::problem::{OptProblemKind, SolutionKind};
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+pub mod algorithms;
+pub mod reductions;
+
+/// Knapsack instance whose items are grouped into classes, exactly one item of
+/// every class has to be packed.
+#[derive(Clone, Debug)]
+pub struct Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    classes: Vec<Vec<I>>,
+    size: W,
+    cost_type: PhantomData<C>,
+}
+
+impl<I, C, W> Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    pub fn new(classes: Vec<Vec<I>>, size: W) -> Self {
+        if classes.iter().any(|class| class.is_empty()) {
+            panic!("Classes must not be empty!");
+        }
+        if classes
+            .iter()
+            .flatten()
+            .any(|item| *item.weight() < W::zero())
+        {
+            panic!("Item weights must be non-negative!");
+        }
+        Instance {
+            classes,
+            size,
+            cost_type: PhantomData,
+        }
+    }
+
+    pub fn classes(&self) -> &[Vec<I>] {
+        &self.classes
+    }
+
+    pub fn number_of_classes(&self) -> usize {
+        self.classes.len()
+    }
+
+    pub fn number_of_items(&self) -> usize {
+        self.classes.iter().map(|class| class.len()).sum()
+    }
+
+    pub fn bag_size(&self) -> &W {
+        &self.size
+    }
+
+    /// Whether `choices` picks an existing item of every class and fits into the bag.
+    pub fn is_feasible(&self, choices: &[usize]) -> bool {
+        if choices.len() != self.classes.len() {
+            return false;
+        }
+        let mut weight = W::zero();
+        for (class, &choice) in self.classes.iter().zip(choices.iter()) {
+            match class.get(choice) {
+                Some(item) => weight += *item.weight(),
+                None => return false,
+            }
+        }
+        weight <= self.size
+    }
+}
+
+impl<I, C, W> Display for Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Multiple-choice knapsack instance\n   - size = {}\n   - classes = {{ {} }}",
+            self.size,
+            self.classes
+                .iter()
+                .map(|class| format!(
+                    "[{}]",
+                    class
+                        .iter()
+                        .map(|item| item.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+impl<I, C, W> OptProblemKind for Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    type Solution = Solution;
+    type Cost = C;
+}
+
+#[derive(Clone, Debug)]
+pub enum Solution {
+    /// `choices[k]` is the index of the chosen item within class `k`.
+    Solved {
+        choices: Vec<usize>,
+    },
+    Infeasible,
+    Failed(String),
+}
+
+impl Solution {
+    pub fn is_solved(&self) -> bool {
+        matches!(self, Solution::Solved { .. })
+    }
+
+    pub fn as_solution(self) -> Option<Vec<usize>> {
+        match self {
+            Solution::Solved { choices } => Some(choices),
+            _ => None,
+        }
+    }
+}
+
+impl<I, C, W> SolutionKind<Instance<I, C, W>> for Solution
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn cost(&self, instance: &Instance<I, C, W>) -> Option<C> {
+        match self {
+            Solution::Infeasible | Solution::Failed(_) => None,
+            Solution::Solved { choices } => Some(
+                instance
+                    .classes()
+                    .iter()
+                    .zip(choices.iter())
+                    .map(|(class, &choice)| *class[choice].cost())
+                    .sum(),
+            ),
+        }
+    }
+}
+
+impl<T> From<(Vec<Vec<(T, T)>>, T)> for Instance<DefaultItem<T>, T, T>
+where
+    T: Numeric,
+{
+    fn from(input: (Vec<Vec<(T, T)>>, T)) -> Self {
+        let classes = input
+            .0
+            .into_iter()
+            .map(|class| class.into_iter().map(DefaultItem::from).collect())
+            .collect();
+        Instance::new(classes, input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_instance_from_works() {
+        let instance = Instance::from((vec![vec![(1, 2), (3, 4)], vec![(0, 0), (2, 3)]], 6));
+        assert_eq!(2, instance.number_of_classes());
+        assert_eq!(4, instance.number_of_items());
+        assert!(instance.is_feasible(&[1, 0]));
+        assert!(!instance.is_feasible(&[1, 1]));
+        assert!(!instance.is_feasible(&[2, 0]));
+        let solution = Solution::Solved {
+            choices: vec![0, 1],
+        };
+        assert_eq!(Some(3), solution.cost(&instance));
+    }
+}
@@ -0,0 +1,142 @@
+use crate::knapsack::multiple_choice::{Instance, Solution};
+use crate::knapsack::reductions::{decode_solution, IlpSolution};
+use crate::knapsack::Item;
+use crate::primitives::Numeric;
+use crate::program::model::{LinearExpr, Sense, Variable};
+use crate::program::{LpSolution, MathProgram};
+use crate::reduction::Reduction;
+
+impl<I, C, W> Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    /// Binary variables `x_{k,j}`, indexed as `vars[k][j]`.
+    pub(super) fn choice_variables(&self, program: &mut MathProgram) -> Vec<Vec<Variable>> {
+        self.classes
+            .iter()
+            .enumerate()
+            .map(|(class, items)| {
+                (0..items.len())
+                    .map(|item| program.add_binary(&format!("x_{}_{}", class, item)))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl<I, C, W> Reduction<MathProgram> for Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn reduce_instance(&self) -> MathProgram {
+        let mut model = MathProgram::new("multiple_choice_knapsack", Sense::Maximize);
+        let vars = self.choice_variables(&mut model);
+
+        model.set_objective(
+            self.classes
+                .iter()
+                .zip(vars.iter())
+                .flat_map(|(items, vars)| {
+                    items
+                        .iter()
+                        .zip(vars.iter())
+                        .map(|(item, var)| (*item.cost()).into() * *var)
+                })
+                .sum::<LinearExpr>(),
+        );
+        for class in vars.iter() {
+            model.add_constraint(
+                class
+                    .iter()
+                    .map(|var| LinearExpr::from(*var))
+                    .sum::<LinearExpr>()
+                    .equal(1.0),
+            );
+        }
+        model.add_constraint(
+            self.classes
+                .iter()
+                .zip(vars.iter())
+                .flat_map(|(items, vars)| {
+                    items
+                        .iter()
+                        .zip(vars.iter())
+                        .map(|(item, var)| (*item.weight()).into() * *var)
+                })
+                .sum::<LinearExpr>()
+                .le(self.size.into()),
+        );
+
+        model
+    }
+
+    fn reduce_solution(&self, solution: &LpSolution) -> Solution {
+        let variables = self.classes.iter().map(|items| items.len()).sum();
+        decode_solution(solution, "multiple-choice knapsack", variables, |values| {
+            let mut choices = Vec::with_capacity(self.classes.len());
+            let mut offset = 0;
+            for items in &self.classes {
+                let class = &values[offset..offset + items.len()];
+                choices.push(class.iter().rposition(|&value| value > 0.0).unwrap_or(0));
+                offset += items.len();
+            }
+            Solution::Solved { choices }
+        })
+    }
+}
+
+impl IlpSolution for Solution {
+    fn infeasible() -> Self {
+        Solution::Infeasible
+    }
+
+    fn failed(message: String) -> Self {
+        Solution::Failed(message)
+    }
+}
+
+#[cfg(test)]
+mod test_super {
+    use super::*;
+    use crate::problem::SolutionKind;
+    use crate::program::branch_and_bound::BranchAndBound;
+
+    #[test]
+    fn reduction_works() {
+        let instance = Instance::from((
+            vec![
+                vec![(1.0, 2.0), (3.0, 4.0)],
+                vec![(0.0, 0.0), (2.0, 3.0), (4.0, 5.0)],
+            ],
+            6.0,
+        ));
+        let program = instance.reduce_instance();
+        assert_eq!(5, program.number_of_variables());
+        assert_eq!(3, program.number_of_constraints());
+    }
+
+    #[test]
+    fn solving_by_reduction_works() {
+        let instance = Instance::from((
+            vec![
+                vec![(1.0, 2.0), (3.0, 4.0)],
+                vec![(0.0, 0.0), (1.0, 3.0), (4.0, 5.0)],
+            ],
+            7.0,
+        ));
+        let solution = instance.solve_by_reduction(&BranchAndBound::default());
+        assert_eq!(Some(vec![0, 2]), solution.clone().as_solution());
+        assert_eq!(Some(5.0), solution.cost(&instance));
+    }
+
+    #[test]
+    fn infeasible_instance_is_detected() {
+        let instance = Instance::from((vec![vec![(1.0, 2.0)], vec![(2.0, 3.0)]], 4.0));
+        let solution = instance.solve_by_reduction(&BranchAndBound::default());
+        assert!(matches!(solution, Solution::Infeasible));
+    }
+}
@@ -0,0 +1,237 @@
+use crate::knapsack::{Instance, Item, Solution};
+use crate::primitives::Numeric;
+
+/// Instance left after [`preprocess`] together with the decisions already taken.
+#[derive(Clone, Debug)]
+pub struct Preprocessed<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    instance: Instance<I, C, W>,
+    original_indices: Vec<usize>,
+    fixed_items: Vec<usize>,
+}
+
+impl<I, C, W> Preprocessed<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    /// Instance over the undecided items whose bag size is reduced by the weight of
+    /// the fixed items.
+    pub fn instance(&self) -> &Instance<I, C, W> {
+        &self.instance
+    }
+
+    /// Index of every item of the reduced instance in the original instance.
+    pub fn original_indices(&self) -> &[usize] {
+        &self.original_indices
+    }
+
+    /// Original indices of the items that are packed in every optimal solution.
+    pub fn fixed_items(&self) -> &[usize] {
+        &self.fixed_items
+    }
+
+    /// Translates a solution of the reduced instance into one of the original instance.
+    pub fn restore(&self, solution: Solution) -> Solution {
+        match solution {
+            Solution::Solved { packed_items } => {
+                let mut packed: Vec<usize> = packed_items
+                    .into_iter()
+                    .map(|index| self.original_indices[index])
+                    .chain(self.fixed_items.iter().copied())
+                    .collect();
+                packed.sort_unstable();
+                Solution::Solved {
+                    packed_items: packed,
+                }
+            }
+            solution => solution,
+        }
+    }
+}
+
+/// Reduces the instance before solving it exactly:
+/// - items heavier than the bag and items without positive cost are dropped,
+/// - the reduction tests of Ingargiola and Korsh compare the Dantzig bound with an
+///   item forced into or out of the bag with the greedy lower bound, and fix the
+///   item to the opposite decision if the bound is smaller,
+/// - an item is removed if it does not fit into the bag together with all items
+///   that dominate it, i.e. that are neither heavier nor less valuable, as then it
+///   can be swapped for one of them in every packing.
+///
+/// An optimal solution of the reduced instance becomes an optimal solution of the
+/// original one through [`Preprocessed::restore`].
+pub fn preprocess<I, C, W>(instance: &Instance<I, C, W>) -> Preprocessed<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    let items = instance.items();
+    let size = *instance.bag_size();
+    let cost = |index: usize| -> f64 { (*items[index].cost()).into() };
+    let weight = |index: usize| -> f64 { (*items[index].weight()).into() };
+
+    let mut order: Vec<usize> = (0..instance.number_of_items())
+        .filter(|&index| *items[index].weight() <= size && *items[index].cost() > C::zero())
+        .collect();
+    order.sort_by(|&a, &b| {
+        (cost(b) / weight(b))
+            .partial_cmp(&(cost(a) / weight(a)))
+            .unwrap()
+    });
+
+    let mut prefix_weights = vec![0.0];
+    let mut prefix_costs = vec![0.0];
+    for &index in &order {
+        prefix_weights.push(prefix_weights.last().unwrap() + weight(index));
+        prefix_costs.push(prefix_costs.last().unwrap() + cost(index));
+    }
+    // Dantzig bound of the sorted items except the one at position `skip`.
+    let dantzig = |capacity: f64, skip: usize| -> f64 {
+        let (position, value, used) = if prefix_weights[skip] > capacity {
+            let position = prefix_weights.partition_point(|&w| w <= capacity) - 1;
+            (position, prefix_costs[position], prefix_weights[position])
+        } else {
+            let shifted = capacity + weight(order[skip]);
+            let position = prefix_weights.partition_point(|&w| w <= shifted) - 1;
+            (
+                position,
+                prefix_costs[position] - cost(order[skip]),
+                prefix_weights[position] - weight(order[skip]),
+            )
+        };
+        match order.get(position) {
+            Some(&index) => value + (capacity - used) * cost(index) / weight(index),
+            None => value,
+        }
+    };
+
+    let capacity: f64 = size.into();
+    let mut lower_bound = order.iter().map(|&index| cost(index)).fold(0.0, f64::max);
+    let mut greedy = (0.0, 0.0);
+    for &index in &order {
+        if greedy.0 + weight(index) <= capacity {
+            greedy = (greedy.0 + weight(index), greedy.1 + cost(index));
+        }
+    }
+    lower_bound = lower_bound.max(greedy.1);
+
+    let integral = order.iter().all(|&index| cost(index).fract() == 0.0);
+    let below_lower_bound = |bound: f64| {
+        if integral {
+            (bound + 1e-9).floor() < lower_bound
+        } else {
+            bound < lower_bound - 1e-9 * (1.0 + lower_bound.abs())
+        }
+    };
+
+    let mut fixed_items = Vec::new();
+    let mut free = Vec::new();
+    for (position, &index) in order.iter().enumerate() {
+        if below_lower_bound(dantzig(capacity, position)) {
+            fixed_items.push(index);
+        } else if !below_lower_bound(cost(index) + dantzig(capacity - weight(index), position)) {
+            free.push(index);
+        }
+    }
+    let remaining = fixed_items
+        .iter()
+        .fold(size, |remaining, &index| remaining - *items[index].weight());
+
+    // Items by increasing weight, ties by decreasing cost and index, such that the
+    // dominating items of an item are the ones before it that are at least as valuable.
+    free.sort_by(|&a, &b| {
+        weight(a)
+            .partial_cmp(&weight(b))
+            .unwrap()
+            .then(cost(b).partial_cmp(&cost(a)).unwrap())
+            .then(a.cmp(&b))
+    });
+    let mut by_cost = free.clone();
+    by_cost.sort_by(|&a, &b| cost(b).partial_cmp(&cost(a)).unwrap());
+    let mut rank = vec![0; instance.number_of_items()];
+    for (position, &index) in by_cost.iter().enumerate() {
+        rank[index] = match position {
+            0 => 0,
+            _ if cost(by_cost[position - 1]) == cost(index) => rank[by_cost[position - 1]],
+            _ => position,
+        };
+    }
+    // Fenwick tree over the cost ranks summing up the weights of the items seen so far.
+    let mut tree = vec![0.0; free.len() + 1];
+    let remaining_capacity: f64 = remaining.into();
+    let mut kept = Vec::new();
+    for &index in &free {
+        let mut dominating_weight = 0.0;
+        let mut node = rank[index] + 1;
+        while node > 0 {
+            dominating_weight += tree[node];
+            node &= node - 1;
+        }
+        if dominating_weight + weight(index) <= remaining_capacity {
+            kept.push(index);
+        }
+        let mut node = rank[index] + 1;
+        while node < tree.len() {
+            tree[node] += weight(index);
+            node += node & node.wrapping_neg();
+        }
+    }
+
+    kept.sort_unstable();
+    fixed_items.sort_unstable();
+    Preprocessed {
+        instance: Instance::new(
+            kept.iter().map(|&index| items[index].clone()).collect(),
+            remaining,
+        ),
+        original_indices: kept,
+        fixed_items,
+    }
+}
+
+#[cfg(test)]
+mod test_super {
+    use super::*;
+    use crate::knapsack::algorithms::SimpleDP;
+    use crate::problem::{OptProblemKind, SolutionKind};
+    use rand::distributions::Uniform;
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn preprocessing_works() {
+        let instance = Instance::from((vec![(10, 2), (1, 3), (4, 9), (3, 3), (0, 1)], 7));
+        let preprocessed = preprocess(&instance);
+        assert_eq!(&[0, 3], preprocessed.fixed_items());
+        assert!(preprocessed.original_indices().is_empty());
+        assert_eq!(2, *preprocessed.instance().bag_size());
+
+        let solution = preprocessed.restore(preprocessed.instance().run(SimpleDP));
+        assert_eq!(Some(vec![0, 3]), solution.as_solution());
+    }
+
+    #[test]
+    fn random_validation_preprocessing() {
+        let mut rng = thread_rng();
+        let costs: Vec<u32> = rng.sample_iter(Uniform::new(0, 100)).take(50).collect();
+        let weights: Vec<u32> = rng.sample_iter(Uniform::new(1, 100)).take(50).collect();
+        let size: u32 = rng.sample(Uniform::new(100, 2000));
+        let instance = Instance::from((costs, weights, size));
+        let preprocessed = preprocess(&instance);
+        let solution = preprocessed.restore(preprocessed.instance().run(SimpleDP));
+
+        let packed = solution.clone().as_solution().unwrap();
+        let weight: u32 = packed.iter().map(|&i| *instance.items()[i].weight()).sum();
+        assert!(weight <= size);
+        assert_eq!(
+            instance.run(SimpleDP).cost(&instance),
+            solution.cost(&instance)
+        );
+    }
+}
@@ -0,0 +1,328 @@
+use crate::algorithm::*;
+use crate::knapsack::quadratic::Instance;
+use crate::knapsack::{Item, Solution};
+use crate::primitives::Numeric;
+use crate::problem::SolutionKind;
+use crate::program::branch_and_bound::BranchAndBound;
+use crate::reduction::Reduction;
+
+const EPSILON: f64 = 1e-9;
+
+/// Upper plane bound in the spirit of Gallo, Hammer and Simeone. If at most `k` items
+/// fit into the bag, item `j` contributes at most
+/// `pi_j = c_j + 1/2 * (sum of the k - 1 largest positive p_ij)`, and the fractional
+/// knapsack over these profits bounds the optimum from above.
+pub struct UpperPlane;
+
+impl UpperPlane {
+    pub fn bound<I, C, W>(&self, instance: &Instance<I, C, W>) -> f64
+    where
+        I: Item<C, W>,
+        C: Numeric,
+        W: Numeric,
+    {
+        let n = instance.number_of_items();
+        let mut weights: Vec<f64> = instance
+            .items()
+            .iter()
+            .map(|item| (*item.weight()).into())
+            .collect();
+        let size: f64 = (*instance.bag_size()).into();
+        weights.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let mut cardinality = 0;
+        let mut total = 0.0;
+        for weight in weights {
+            if total + weight > size {
+                break;
+            }
+            total += weight;
+            cardinality += 1;
+        }
+
+        let planes: Vec<(f64, f64)> = (0..n)
+            .map(|j| {
+                let mut profits: Vec<f64> = (0..n)
+                    .map(|i| (*instance.pair_profit(i, j)).into())
+                    .filter(|&profit: &f64| profit > 0.0)
+                    .collect();
+                profits.sort_by(|a, b| b.partial_cmp(a).unwrap());
+                let pairs: f64 = profits.iter().take(cardinality.max(1) - 1).sum();
+                let item = &instance.items()[j];
+                ((*item.cost()).into() + 0.5 * pairs, (*item.weight()).into())
+            })
+            .filter(|&(profit, _)| profit > 0.0)
+            .collect();
+
+        let mut order: Vec<usize> = (0..planes.len()).collect();
+        order.sort_by(|&a, &b| {
+            (planes[b].0 / planes[b].1)
+                .partial_cmp(&(planes[a].0 / planes[a].1))
+                .unwrap()
+        });
+        let mut capacity = size;
+        let mut bound = 0.0;
+        for index in order {
+            let (profit, weight) = planes[index];
+            if weight <= capacity {
+                capacity -= weight;
+                bound += profit;
+            } else {
+                bound += profit * capacity / weight;
+                break;
+            }
+        }
+        bound
+    }
+}
+
+/// Adds the fitting item with the best marginal gain per weight until no item improves
+/// the packing, then applies the best improving addition, removal or swap of a packed
+/// and an unpacked item until a local optimum or the iteration limit is reached.
+pub struct GreedyLocalSearch {
+    max_iterations: Option<usize>,
+}
+
+impl GreedyLocalSearch {
+    pub fn new() -> Self {
+        GreedyLocalSearch {
+            max_iterations: None,
+        }
+    }
+
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = Some(max_iterations);
+        self
+    }
+}
+
+impl Default for GreedyLocalSearch {
+    fn default() -> Self {
+        GreedyLocalSearch::new()
+    }
+}
+
+struct Packing<'a, I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    instance: &'a Instance<I, C, W>,
+    packed: Vec<bool>,
+    weight: W,
+    /// `c_j + sum_{i packed} p_ij` for every item `j`.
+    gains: Vec<f64>,
+}
+
+impl<'a, I, C, W> Packing<'a, I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn new(instance: &'a Instance<I, C, W>) -> Self {
+        Packing {
+            instance,
+            packed: vec![false; instance.number_of_items()],
+            weight: W::zero(),
+            gains: instance
+                .items()
+                .iter()
+                .map(|item| (*item.cost()).into())
+                .collect(),
+        }
+    }
+
+    fn weight(&self, item: usize) -> W {
+        *self.instance.items()[item].weight()
+    }
+
+    fn profit(&self, a: usize, b: usize) -> f64 {
+        (*self.instance.pair_profit(a, b)).into()
+    }
+
+    /// Whether `add` fits after removing `remove`.
+    fn fits(&self, add: usize, remove: Option<usize>) -> bool {
+        let freed = remove.map_or(W::zero(), |item| self.weight(item));
+        self.weight + self.weight(add) <= *self.instance.bag_size() + freed
+    }
+
+    fn toggle(&mut self, item: usize) {
+        let sign = if self.packed[item] { -1.0 } else { 1.0 };
+        self.packed[item] = !self.packed[item];
+        self.weight = (0..self.packed.len())
+            .filter(|&index| self.packed[index])
+            .map(|index| self.weight(index))
+            .sum();
+        for j in 0..self.gains.len() {
+            self.gains[j] += sign * self.profit(item, j);
+        }
+    }
+
+    fn greedy(&mut self) {
+        loop {
+            let candidate = (0..self.packed.len())
+                .filter(|&j| !self.packed[j] && self.gains[j] > EPSILON && self.fits(j, None))
+                .map(|j| (j, self.gains[j] / self.weight(j).into()))
+                .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+            match candidate {
+                Some((j, _)) => self.toggle(j),
+                None => break,
+            }
+        }
+    }
+
+    /// Best improving move as `(remove, add)` and its gain.
+    fn best_move(&self) -> Option<(Option<usize>, Option<usize>)> {
+        let n = self.packed.len();
+        let mut best: (Option<usize>, Option<usize>) = (None, None);
+        let mut best_gain = EPSILON;
+        for i in (0..n).filter(|&i| self.packed[i]) {
+            if -self.gains[i] > best_gain {
+                best = (Some(i), None);
+                best_gain = -self.gains[i];
+            }
+        }
+        for j in (0..n).filter(|&j| !self.packed[j]) {
+            if self.gains[j] > best_gain && self.fits(j, None) {
+                best = (None, Some(j));
+                best_gain = self.gains[j];
+            }
+            for i in (0..n).filter(|&i| self.packed[i]) {
+                let gain = self.gains[j] - self.gains[i] - self.profit(i, j);
+                if gain > best_gain && self.fits(j, Some(i)) {
+                    best = (Some(i), Some(j));
+                    best_gain = gain;
+                }
+            }
+        }
+        match best {
+            (None, None) => None,
+            _ => Some(best),
+        }
+    }
+}
+
+impl<I, C, W> Algorithm<Instance<I, C, W>> for GreedyLocalSearch
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn run(&self, instance: &Instance<I, C, W>) -> Solution {
+        let mut packing = Packing::new(instance);
+        packing.greedy();
+        let mut iterations = 0;
+        while iterations < self.max_iterations.unwrap_or(usize::MAX) {
+            match packing.best_move() {
+                Some((remove, add)) => {
+                    if let Some(i) = remove {
+                        packing.toggle(i);
+                    }
+                    if let Some(j) = add {
+                        packing.toggle(j);
+                    }
+                }
+                None => break,
+            }
+            iterations += 1;
+        }
+        Solution::Solved {
+            packed_items: (0..instance.number_of_items())
+                .filter(|&index| packing.packed[index])
+                .collect(),
+        }
+    }
+}
+
+impl<I, C, W> TheoreticValidation<Instance<I, C, W>> for GreedyLocalSearch
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn validate(&self, instance: &Instance<I, C, W>, solution: &Solution) -> TheoreticGuarantee {
+        if let Solution::Solved { packed_items } = solution {
+            if !instance.is_feasible(packed_items) {
+                return TheoreticGuarantee::Inconsistent(String::from(
+                    "Packed items exceed the bag size",
+                ));
+            }
+        }
+        let optimal_solution = instance.solve_by_reduction(&BranchAndBound::default());
+        let bound = UpperPlane.bound(instance);
+        match (solution.cost(instance), optimal_solution.cost(instance)) {
+            (Some(alg), Some(opt)) => {
+                let (alg, opt) = (alg.into(), opt.into());
+                if alg > opt + 1e-6 || opt > bound + 1e-6 {
+                    TheoreticGuarantee::Inconsistent(format!(
+                        "Heuristic value, optimum and upper plane bound are not ordered: {} <= {} <= {} does not hold",
+                        alg, opt, bound
+                    ))
+                } else {
+                    TheoreticGuarantee::Consistent
+                }
+            }
+            _ => TheoreticGuarantee::Failed(String::from(
+                "Error: Cost of optimal solution and algorithm could not have been computed!",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_super {
+    use super::*;
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn upper_plane_works() {
+        let instance = Instance::from((
+            vec![(1, 1), (2, 2), (3, 3), (1, 2)],
+            5,
+            vec![
+                vec![0, 4, 0, 3],
+                vec![4, 0, 1, -2],
+                vec![0, 1, 0, 0],
+                vec![3, -2, 0, 0],
+            ],
+        ));
+        // at most three items fit, pi = (4.5, 4.5, 3.5, 2.5)
+        assert!((UpperPlane.bound(&instance) - 11.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn greedy_local_search_works() {
+        let instance = Instance::from((
+            vec![(1, 1), (2, 2), (3, 3), (1, 2)],
+            5,
+            vec![
+                vec![0, 4, 0, 3],
+                vec![4, 0, 1, -2],
+                vec![0, 1, 0, 0],
+                vec![3, -2, 0, 0],
+            ],
+        ));
+        let solution = GreedyLocalSearch::default().run(&instance);
+        assert_eq!(Some(vec![0, 1, 3]), solution.clone().as_solution());
+        assert_eq!(Some(9), solution.cost(&instance));
+    }
+
+    #[test]
+    fn random_validation_greedy_local_search_alg() {
+        let mut rng = thread_rng();
+        let items: Vec<(i32, i32)> = (0..12)
+            .map(|_| (rng.gen_range(0, 10), rng.gen_range(1, 10)))
+            .collect();
+        let mut profits = vec![vec![0; 12]; 12];
+        for (i, j) in (0..12).flat_map(|i| (0..i).map(move |j| (i, j))) {
+            profits[i][j] = rng.gen_range(-3, 8);
+            profits[j][i] = profits[i][j];
+        }
+        let instance = Instance::from((items, rng.gen_range(10, 40), profits));
+        let algorithm = GreedyLocalSearch::new().with_max_iterations(20);
+        let solution = algorithm.run(&instance);
+        assert!(solution.is_solved());
+        assert!(algorithm.validate(&instance, &solution).is_correct());
+    }
+}
@@ -0,0 +1,174 @@
+use crate::knapsack::{DefaultItem, Item, Solution};
+use crate::primitives::Numeric;
+use crate::problem::{OptProblemKind, SolutionKind};
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+pub mod algorithms;
+pub mod reductions;
+
+/// Knapsack instance where packing items `i` and `j` together yields the additional
+/// profit `pair_profits[i][j]`.
+#[derive(Clone, Debug)]
+pub struct Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    items: Vec<I>,
+    size: W,
+    pair_profits: Vec<Vec<C>>,
+    cost_type: PhantomData<C>,
+}
+
+impl<I, C, W> Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    pub fn new(items: Vec<I>, size: W, pair_profits: Vec<Vec<C>>) -> Self {
+        if items.iter().any(|item| *item.weight() <= W::zero()) {
+            panic!("Item weights must be positive!");
+        }
+        if pair_profits.len() != items.len()
+            || pair_profits.iter().any(|row| row.len() != items.len())
+        {
+            panic!("Pair profits must form a square matrix over the items!");
+        }
+        let symmetric = pair_profits.iter().enumerate().all(|(i, row)| {
+            row[i] == C::zero()
+                && row
+                    .iter()
+                    .enumerate()
+                    .all(|(j, profit)| *profit == pair_profits[j][i])
+        });
+        if !symmetric {
+            panic!("Pair profits must be symmetric with a zero diagonal!");
+        }
+        Instance {
+            items,
+            size,
+            pair_profits,
+            cost_type: PhantomData,
+        }
+    }
+
+    pub fn items(&self) -> &[I] {
+        &self.items
+    }
+
+    pub fn number_of_items(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn bag_size(&self) -> &W {
+        &self.size
+    }
+
+    pub fn pair_profit(&self, a: usize, b: usize) -> &C {
+        &self.pair_profits[a][b]
+    }
+
+    pub fn is_feasible(&self, packed_items: &[usize]) -> bool {
+        packed_items.iter().all(|&item| item < self.items.len())
+            && packed_items
+                .iter()
+                .map(|&item| *self.items[item].weight())
+                .sum::<W>()
+                <= self.size
+    }
+}
+
+impl<I, C, W> Display for Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Quadratic knapsack instance\n   - size = {}\n   - items = {{ {} }}\n   - pair profits = [{}]",
+            self.size,
+            self.items
+                .iter()
+                .map(|item| item.to_string())
+                .collect::<Vec<String>>()
+                .join(", "),
+            self.pair_profits
+                .iter()
+                .map(|row| row
+                    .iter()
+                    .map(|profit| profit.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" "))
+                .collect::<Vec<String>>()
+                .join("; ")
+        )
+    }
+}
+
+impl<I, C, W> OptProblemKind for Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    type Solution = Solution;
+    type Cost = C;
+}
+
+impl<I, C, W> SolutionKind<Instance<I, C, W>> for Solution
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn cost(&self, instance: &Instance<I, C, W>) -> Option<C> {
+        match self {
+            Solution::Infeasible | Solution::Failed(_) => None,
+            Solution::Solved { packed_items } => {
+                let mut cost = C::zero();
+                for (k, &i) in packed_items.iter().enumerate() {
+                    cost += *instance.items()[i].cost();
+                    for &j in &packed_items[k + 1..] {
+                        cost += *instance.pair_profit(i, j);
+                    }
+                }
+                Some(cost)
+            }
+        }
+    }
+}
+
+impl<T> From<(Vec<(T, T)>, T, Vec<Vec<T>>)> for Instance<DefaultItem<T>, T, T>
+where
+    T: Numeric,
+{
+    fn from(input: (Vec<(T, T)>, T, Vec<Vec<T>>)) -> Self {
+        let items = input.0.into_iter().map(DefaultItem::from).collect();
+        Instance::new(items, input.1, input.2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cost_includes_pair_profits() {
+        let instance = Instance::from((
+            vec![(1, 1), (2, 2), (3, 3)],
+            5,
+            vec![vec![0, 4, 0], vec![4, 0, 1], vec![0, 1, 0]],
+        ));
+        let solution = Solution::Solved {
+            packed_items: vec![0, 1, 2],
+        };
+        assert_eq!(Some(11), solution.cost(&instance));
+        assert!(instance.is_feasible(&[1, 2]));
+        assert!(!instance.is_feasible(&[0, 1, 2]));
+    }
+}
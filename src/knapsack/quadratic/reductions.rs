@@ -0,0 +1,271 @@
+use crate::knapsack::quadratic::Instance;
+use crate::knapsack::reductions::{decode_solution, packed_items};
+use crate::knapsack::{Item, Solution};
+use crate::primitives::Numeric;
+use crate::problem::{OptProblemKind, SolutionKind};
+use crate::program::model::{LinearExpr, Sense, Variable};
+use crate::program::{LpSolution, MathProgram};
+use crate::reduction::Reduction;
+
+/// ILP reduction of a quadratic knapsack instance with one variable `y_ij = x_i * x_j`
+/// per non-zero pair profit, bounded by its McCormick envelope. This is also the
+/// reduction of the instance itself.
+#[derive(Clone, Copy, Debug)]
+pub struct McCormick<'a, I, C, W>(pub &'a Instance<I, C, W>)
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric;
+
+/// ILP reduction of a quadratic knapsack instance with one variable
+/// `z_i = x_i * sum_{j > i} p_ij x_j` per item after Glover, which needs fewer
+/// variables and constraints than [`McCormick`] on dense instances.
+#[derive(Clone, Copy, Debug)]
+pub struct Glover<'a, I, C, W>(pub &'a Instance<I, C, W>)
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric;
+
+impl<I, C, W> Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn item_variables(&self, program: &mut MathProgram) -> Vec<Variable> {
+        (0..self.items.len())
+            .map(|index| program.add_binary(&format!("x_{}", index)))
+            .collect()
+    }
+
+    fn profit(&self, a: usize, b: usize) -> f64 {
+        self.pair_profits[a][b].into()
+    }
+
+    /// McCormick envelope of `y_ij = x_i * x_j` for every non-zero pair profit.
+    fn mccormick_terms(&self, model: &mut MathProgram, vars: &[Variable]) -> LinearExpr {
+        let mut objective = LinearExpr::from(0.0);
+        for i in 0..vars.len() {
+            for j in i + 1..vars.len() {
+                let profit = self.profit(i, j);
+                if profit == 0.0 {
+                    continue;
+                }
+                let y = model.add_continuous(&format!("y_{}_{}", i, j), Some(0.0), Some(1.0));
+                model.add_constraint(LinearExpr::from(y).le(vars[i]));
+                model.add_constraint(LinearExpr::from(y).le(vars[j]));
+                model.add_constraint(LinearExpr::from(y).ge(vars[i] + vars[j] - 1.0));
+                objective += profit * y;
+            }
+        }
+        objective
+    }
+
+    /// Glover's linearization of `z_i = x_i * sum_{j > i} p_ij x_j` with the bounds
+    /// `L_i` and `U_i` of the sum.
+    fn glover_terms(&self, model: &mut MathProgram, vars: &[Variable]) -> LinearExpr {
+        let mut objective = LinearExpr::from(0.0);
+        for i in 0..vars.len() {
+            let profits: Vec<(Variable, f64)> = (i + 1..vars.len())
+                .map(|j| (vars[j], self.profit(i, j)))
+                .filter(|&(_, profit)| profit != 0.0)
+                .collect();
+            if profits.is_empty() {
+                continue;
+            }
+            let upper: f64 = profits.iter().map(|&(_, p)| p.max(0.0)).sum();
+            let lower: f64 = profits.iter().map(|&(_, p)| p.min(0.0)).sum();
+            let sum = || profits.iter().map(|&(var, p)| p * var).sum::<LinearExpr>();
+            let z = model.add_continuous(&format!("z_{}", i), Some(lower), Some(upper));
+            model.add_constraint(LinearExpr::from(z).le(upper * vars[i]));
+            model.add_constraint(LinearExpr::from(z).ge(lower * vars[i]));
+            model.add_constraint(LinearExpr::from(z).le(sum() - lower + lower * vars[i]));
+            model.add_constraint(LinearExpr::from(z).ge(sum() - upper + upper * vars[i]));
+            objective += z;
+        }
+        objective
+    }
+}
+
+impl<I, C, W> Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    /// ILP over the item variables whose pairwise terms are linearized by `pairs`.
+    fn program<F>(&self, pairs: F) -> MathProgram
+    where
+        F: FnOnce(&Self, &mut MathProgram, &[Variable]) -> LinearExpr,
+    {
+        let mut model = MathProgram::new("quadratic_knapsack", Sense::Maximize);
+        let vars = self.item_variables(&mut model);
+
+        let linear = self
+            .items
+            .iter()
+            .zip(vars.iter())
+            .map(|(item, var)| (*item.cost()).into() * *var)
+            .sum::<LinearExpr>();
+        let pairs = pairs(self, &mut model, &vars);
+        model.set_objective(linear + pairs);
+        model.add_constraint(
+            self.items
+                .iter()
+                .zip(vars.iter())
+                .map(|(item, var)| (*item.weight()).into() * *var)
+                .sum::<LinearExpr>()
+                .le(self.size.into()),
+        );
+
+        model
+    }
+
+    fn decode(&self, solution: &LpSolution) -> Solution {
+        decode_solution(solution, "quadratic knapsack", self.items.len(), |values| {
+            Solution::Solved {
+                packed_items: packed_items(&values),
+            }
+        })
+    }
+}
+
+impl<I, C, W> Reduction<MathProgram> for Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn reduce_instance(&self) -> MathProgram {
+        McCormick(self).reduce_instance()
+    }
+
+    fn reduce_solution(&self, solution: &LpSolution) -> Solution {
+        self.decode(solution)
+    }
+}
+
+impl<'a, I, C, W> OptProblemKind for McCormick<'a, I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    type Solution = Solution;
+    type Cost = C;
+}
+
+impl<'a, I, C, W> SolutionKind<McCormick<'a, I, C, W>> for Solution
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn cost(&self, reduction: &McCormick<'a, I, C, W>) -> Option<C> {
+        self.cost(reduction.0)
+    }
+}
+
+impl<'a, I, C, W> Reduction<MathProgram> for McCormick<'a, I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn reduce_instance(&self) -> MathProgram {
+        self.0.program(Instance::mccormick_terms)
+    }
+
+    fn reduce_solution(&self, solution: &LpSolution) -> Solution {
+        self.0.decode(solution)
+    }
+}
+
+impl<'a, I, C, W> OptProblemKind for Glover<'a, I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    type Solution = Solution;
+    type Cost = C;
+}
+
+impl<'a, I, C, W> SolutionKind<Glover<'a, I, C, W>> for Solution
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn cost(&self, reduction: &Glover<'a, I, C, W>) -> Option<C> {
+        self.cost(reduction.0)
+    }
+}
+
+impl<'a, I, C, W> Reduction<MathProgram> for Glover<'a, I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn reduce_instance(&self) -> MathProgram {
+        self.0.program(Instance::glover_terms)
+    }
+
+    fn reduce_solution(&self, solution: &LpSolution) -> Solution {
+        self.0.decode(solution)
+    }
+}
+
+#[cfg(test)]
+mod test_super {
+    use super::*;
+    use crate::problem::SolutionKind;
+    use crate::program::branch_and_bound::BranchAndBound;
+
+    #[test]
+    fn reduction_works() {
+        let instance = Instance::from((
+            vec![(1.0, 1.0), (2.0, 2.0), (3.0, 3.0), (1.0, 2.0)],
+            5.0,
+            vec![
+                vec![0.0, 4.0, 0.0, 3.0],
+                vec![4.0, 0.0, 1.0, -2.0],
+                vec![0.0, 1.0, 0.0, 0.0],
+                vec![3.0, -2.0, 0.0, 0.0],
+            ],
+        ));
+        let program = instance.reduce_instance();
+        assert_eq!(8, program.number_of_variables());
+        assert_eq!(13, program.number_of_constraints());
+        let program = McCormick(&instance).reduce_instance();
+        assert_eq!(8, program.number_of_variables());
+        let program = Glover(&instance).reduce_instance();
+        assert_eq!(6, program.number_of_variables());
+        assert_eq!(9, program.number_of_constraints());
+    }
+
+    #[test]
+    fn solving_by_reduction_works() {
+        let instance = Instance::from((
+            vec![(1.0, 1.0), (2.0, 2.0), (3.0, 3.0), (1.0, 2.0)],
+            5.0,
+            vec![
+                vec![0.0, 4.0, 0.0, 3.0],
+                vec![4.0, 0.0, 1.0, -2.0],
+                vec![0.0, 1.0, 0.0, 0.0],
+                vec![3.0, -2.0, 0.0, 0.0],
+            ],
+        ));
+        let solutions = vec![
+            instance.solve_by_reduction(&BranchAndBound::default()),
+            McCormick(&instance).solve_by_reduction(&BranchAndBound::default()),
+            Glover(&instance).solve_by_reduction(&BranchAndBound::default()),
+        ];
+        for solution in solutions {
+            assert_eq!(Some(vec![0, 1, 3]), solution.clone().as_solution());
+            assert_eq!(Some(9.0), solution.cost(&instance));
+        }
+    }
+}
@@ -1,9 +1,78 @@
 use crate::knapsack::{Instance, Item, Solution};
 use crate::primitives::Numeric;
+use crate::program::model::{LinearExpr, Sense, Variable};
 use crate::program::{LpSolution, MathProgram};
 use crate::reduction::Reduction;
 
-use lp_modeler::dsl::*;
+impl<I, C, W> Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn item_variables(&self, program: &mut MathProgram) -> Vec<Variable> {
+        (0..self.items.len())
+            .map(|index| program.add_binary(&format!("x_{}", index)))
+            .collect()
+    }
+}
+
+/// Solution of a knapsack variant that can be built from the outcome of its ILP.
+pub(crate) trait IlpSolution {
+    fn infeasible() -> Self;
+    fn failed(message: String) -> Self;
+}
+
+impl IlpSolution for Solution {
+    fn infeasible() -> Self {
+        Solution::Infeasible
+    }
+
+    fn failed(message: String) -> Self {
+        Solution::Failed(message)
+    }
+}
+
+/// Decodes the solution of the ILP of a knapsack variant whose first `variables`
+/// variables are its integral decision variables. Their values are passed to `decode`,
+/// a fractional value makes the solution fail.
+pub(crate) fn decode_solution<S, F>(
+    solution: &LpSolution,
+    problem: &str,
+    variables: usize,
+    decode: F,
+) -> S
+where
+    S: IlpSolution,
+    F: FnOnce(Vec<f64>) -> S,
+{
+    match solution {
+        LpSolution::Failed(msg) => S::failed(String::from(msg)),
+        LpSolution::Infeasible => S::infeasible(),
+        LpSolution::Unbounded => panic!(
+            "LP solution unbounded for {} instance. This should not happen!",
+            problem
+        ),
+        LpSolution::Solved { .. } => {
+            let mut values = Vec::with_capacity(variables);
+            for index in 0..variables {
+                let var = Variable::new(index);
+                match solution.integral_value(var) {
+                    Some(value) => values.push(value),
+                    None => return S::failed(format!("Variable {} has a fractional value", index)),
+                }
+            }
+            decode(values)
+        }
+    }
+}
+
+/// Indices of the variables with positive value.
+pub(crate) fn packed_items(values: &[f64]) -> Vec<usize> {
+    (0..values.len())
+        .filter(|&index| values[index] > 0.0)
+        .collect()
+}
 
 impl<I, C, W> Reduction<MathProgram> for Instance<I, C, W>
 where
@@ -12,52 +81,42 @@ where
     W: Numeric,
 {
     fn reduce_instance(&self) -> MathProgram {
-        let mut model = LpProblem::new("knapsack", LpObjective::Maximize);
-        let vars: Vec<(&I, LpBinary)> = self
-            .items
-            .iter()
-            .enumerate()
-            .map(|(index, item)| (item, LpBinary::new(&format!("x_{}", index))))
-            .collect();
-
-        let obj_vec: Vec<LpExpression> = vars
-            .iter()
-            .map(|(item, var)| (*item.cost()).into() as f32 * var)
-            .collect();
-        model += obj_vec.sum();
-
-        model += sum(&vars, |(item, var)| (*item.weight()).into() as f32 * var)
-            .le(self.size.into() as f32);
-
-        model.into()
+        let mut model = MathProgram::new("knapsack", Sense::Maximize);
+        let vars = self.item_variables(&mut model);
+
+        model.set_objective(
+            self.items
+                .iter()
+                .zip(vars.iter())
+                .map(|(item, var)| (*item.cost()).into() * *var)
+                .sum::<LinearExpr>(),
+        );
+        model.add_constraint(
+            self.items
+                .iter()
+                .zip(vars.iter())
+                .map(|(item, var)| (*item.weight()).into() * *var)
+                .sum::<LinearExpr>()
+                .le(self.size.into()),
+        );
+
+        model
     }
 
     fn reduce_solution(&self, solution: &LpSolution) -> Solution {
-        match solution {
-            LpSolution::Failed(msg) => Solution::Failed(String::from(msg)),
-            LpSolution::Infeasible => Solution::Infeasible,
-            LpSolution::Unbounded => {
-                panic!("LP solution unbounded for knapsack instance. This should not happen!")
-            }
-            LpSolution::Solved { vars, .. } => {
-                let packed = self
-                    .items
-                    .iter()
-                    .enumerate()
-                    .filter(|(index, _)| *vars.get(&format!("x_{}", index)).unwrap() == 1.0)
-                    .map(|(index, _)| index)
-                    .collect();
-                Solution::Solved {
-                    packed_items: packed,
-                }
+        decode_solution(solution, "knapsack", self.items.len(), |values| {
+            Solution::Solved {
+                packed_items: packed_items(&values),
             }
-        }
+        })
     }
 }
 
 #[cfg(test)]
 mod test_super {
     use super::*;
+    use crate::algorithm::Algorithm;
+    use crate::program::branch_and_bound::{BranchAndBound, NodeSelection};
     use crate::program::LpSolver;
 
     #[test]
@@ -71,9 +130,56 @@ mod test_super {
     #[test]
     fn solving_by_reduction_works() {
         let instance = &Instance::from((vec![(1.0, 2.0), (2.0, 3.0), (2.0, 4.0)], 5.0));
-        let solution = instance.solve_by_reduction(&LpSolver::CBC);
+        let solution = instance.solve_by_reduction(&BranchAndBound::default());
         assert!(solution.is_solved());
         let items = solution.as_solution().unwrap();
         assert_eq!(items, vec![0, 1])
     }
+
+    #[test]
+    fn solving_by_depth_first_reduction_works() {
+        let instance = &Instance::from((vec![(1.0, 2.0), (2.0, 3.0), (2.0, 4.0)], 5.0));
+        let solution = instance.solve_by_reduction(&BranchAndBound::new(NodeSelection::DepthFirst));
+        assert!(solution.is_solved());
+        let items = solution.as_solution().unwrap();
+        assert_eq!(items, vec![0, 1])
+    }
+
+    #[test]
+    fn solving_large_values_by_reduction_works() {
+        let instance =
+            &Instance::from((vec![(16777217, 16777217), (16777216, 16777216)], 16777217));
+        let solution = instance.solve_by_reduction(&BranchAndBound::default());
+        assert_eq!(Some(vec![0]), solution.as_solution());
+    }
+
+    #[test]
+    fn solving_relaxation_by_simplex_works() {
+        let instance = &Instance::from((vec![(1.0, 2.0), (2.0, 3.0), (2.0, 4.0)], 5.0));
+        let solution = LpSolver::Simplex.run(&instance.reduce_instance());
+        match solution {
+            LpSolution::Solved { value, .. } => assert!((value.unwrap() - 3.0).abs() < 1e-6),
+            _ => panic!("LP relaxation of knapsack instance was not solved"),
+        }
+    }
+
+    #[test]
+    fn cost_ranging_of_relaxation_works() {
+        let instance = &Instance::from((vec![(10.0, 5.0), (6.0, 4.0), (4.0, 4.0)], 10.0));
+        let program = instance.reduce_instance();
+        let solution = LpSolver::Simplex.run(&program);
+        let duals = solution.duals().unwrap();
+        let items: Vec<Variable> = program.variables().collect();
+        let close = |a: Option<f64>, b: Option<f64>| match (a, b) {
+            (Some(a), Some(b)) => (a - b).abs() < 1e-6,
+            (a, b) => a.is_none() && b.is_none(),
+        };
+
+        let (lower, upper) = duals.objective_range(items[0]);
+        assert!(close(Some(5.0), lower) && close(None, upper));
+        let (lower, upper) = duals.objective_range(items[1]);
+        assert!(close(Some(4.0), lower) && close(None, upper));
+        let (lower, upper) = duals.objective_range(items[2]);
+        assert!(close(Some(0.0), lower) && close(Some(6.0), upper));
+    }
 }
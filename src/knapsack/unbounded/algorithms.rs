@@ -0,0 +1,245 @@
+use crate::algorithm::*;
+use crate::knapsack::bounded::multiple;
+use crate::knapsack::unbounded::{Instance, Solution};
+use crate::knapsack::Item;
+use crate::primitives::Numeric;
+use crate::problem::SolutionKind;
+use crate::program::branch_and_bound::BranchAndBound;
+use crate::reduction::Reduction;
+
+/// Indices, costs and weights of the items that fit into the bag and are not
+/// dominated, i.e. no other item `i` with `floor(w_j / w_i) * c_i >= c_j` exists.
+fn undominated<I, C>(instance: &Instance<I, C, u32>) -> Vec<(usize, C, u32)>
+where
+    I: Item<C, u32>,
+    C: Numeric,
+{
+    let mut items: Vec<(usize, C, u32)> = instance
+        .items()
+        .iter()
+        .enumerate()
+        .map(|(index, item)| (index, *item.cost(), *item.weight()))
+        .filter(|&(_, cost, weight)| weight <= *instance.bag_size() && cost > C::zero())
+        .collect();
+    items.sort_by(|a, b| a.2.cmp(&b.2).then(b.1.partial_cmp(&a.1).unwrap()));
+
+    let mut kept: Vec<(usize, C, u32)> = Vec::new();
+    for (index, cost, weight) in items {
+        let dominated = kept.iter().any(|&(_, other_cost, other_weight)| {
+            multiple(other_cost, weight / other_weight) >= cost
+        });
+        if !dominated {
+            kept.push((index, cost, weight));
+        }
+    }
+    kept
+}
+
+/// Optimal counts for the bag size `capacity` by the recursion
+/// `f(j) = max(f(j - 1), max_i f(j - w_i) + c_i)`.
+fn dynamic_program<C: Numeric>(items: &[(usize, C, u32)], capacity: u32, counts: &mut [u32]) {
+    let capacity = capacity as usize;
+    let mut values = vec![C::zero(); capacity + 1];
+    let mut choices: Vec<Option<usize>> = vec![None; capacity + 1];
+    for j in 1..=capacity {
+        values[j] = values[j - 1];
+        for (k, &(_, cost, weight)) in items.iter().enumerate() {
+            let weight = weight as usize;
+            if weight <= j && values[j - weight] + cost > values[j] {
+                values[j] = values[j - weight] + cost;
+                choices[j] = Some(k);
+            }
+        }
+    }
+
+    let mut j = capacity;
+    while j > 0 {
+        match choices[j] {
+            Some(k) => {
+                counts[items[k].0] += 1;
+                j -= items[k].2 as usize;
+            }
+            None => j -= 1,
+        }
+    }
+}
+
+/// Dynamic program over the bag size on the items left after removing dominated ones.
+pub struct DominanceDP;
+
+impl<I, C> Algorithm<Instance<I, C, u32>> for DominanceDP
+where
+    I: Item<C, u32>,
+    C: Numeric,
+{
+    fn run(&self, instance: &Instance<I, C, u32>) -> Solution {
+        let mut counts = vec![0; instance.number_of_items()];
+        dynamic_program(&undominated(instance), *instance.bag_size(), &mut counts);
+        Solution::Solved { counts }
+    }
+}
+
+/// Exact algorithm for large bag sizes. Some optimal solution packs fewer than `w_b`
+/// items other than the item `b` of the best ratio, since otherwise a subset of them
+/// would have a total weight divisible by `w_b` and could be exchanged for copies of
+/// `b`. Hence all but `w_b * w_max` units of the bag can be filled with `b` and only
+/// the remainder is solved by dynamic programming.
+pub struct Periodicity;
+
+impl<I, C> Algorithm<Instance<I, C, u32>> for Periodicity
+where
+    I: Item<C, u32>,
+    C: Numeric,
+{
+    fn run(&self, instance: &Instance<I, C, u32>) -> Solution {
+        let items = undominated(instance);
+        let mut counts = vec![0; instance.number_of_items()];
+        let best = items.iter().max_by(|a, b| {
+            (a.1.into() / f64::from(a.2))
+                .partial_cmp(&(b.1.into() / f64::from(b.2)))
+                .unwrap()
+        });
+        let (best_index, best_weight) = match best {
+            Some(&(index, _, weight)) => (index, u64::from(weight)),
+            None => return Solution::Solved { counts },
+        };
+        let largest_weight = items.iter().map(|&(_, _, weight)| u64::from(weight)).max();
+        let period = best_weight * largest_weight.unwrap_or(0);
+
+        let size = u64::from(*instance.bag_size());
+        let copies = size.saturating_sub(period) / best_weight;
+        counts[best_index] = copies as u32;
+        dynamic_program(&items, (size - copies * best_weight) as u32, &mut counts);
+        Solution::Solved { counts }
+    }
+}
+
+/// Packs as many copies as possible of every item by decreasing cost-to-weight ratio.
+/// Already the first item that fits fills more than half of the bag with the best
+/// ratio, so the result has at least half of the optimal cost.
+pub struct Greedy;
+
+impl<I, C, W> Algorithm<Instance<I, C, W>> for Greedy
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn run(&self, instance: &Instance<I, C, W>) -> Solution {
+        let mut indexed_items: Vec<(usize, &I)> = instance.items().iter().enumerate().collect();
+        indexed_items.sort_by(|(_, b), (_, a)| {
+            ((*a.cost()).into() / (*a.weight()).into())
+                .partial_cmp(&((*b.cost()).into() / (*b.weight()).into()))
+                .unwrap()
+        });
+
+        let size = *instance.bag_size();
+        let mut weight = W::zero();
+        let mut counts = vec![0; instance.number_of_items()];
+        for (index, item) in indexed_items {
+            if *item.cost() <= C::zero() {
+                continue;
+            }
+            let left = size.into() - weight.into();
+            let mut count = (left / (*item.weight()).into()).floor().max(0.0) as u32;
+            while count > 0 && weight + multiple(*item.weight(), count) > size {
+                count -= 1;
+            }
+            weight += multiple(*item.weight(), count);
+            counts[index] = count;
+        }
+        Solution::Solved { counts }
+    }
+}
+
+impl<I, C, W> TheoreticValidation<Instance<I, C, W>> for Greedy
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn validate(&self, instance: &Instance<I, C, W>, solution: &Solution) -> TheoreticGuarantee {
+        let optimal_solution = instance.solve_by_reduction(&BranchAndBound::default());
+        match (solution.cost(instance), optimal_solution.cost(instance)) {
+            (Some(alg), Some(opt)) => {
+                if alg.into() < 0.5 * opt.into() {
+                    TheoreticGuarantee::Inconsistent(format!("Greedy algorithm did not achieve its theoretical approximation ratio: {} < 0.5 * {}", alg, opt))
+                } else {
+                    TheoreticGuarantee::Consistent
+                }
+            }
+            _ => TheoreticGuarantee::Failed(String::from(
+                "Error: Cost of optimal solution and algorithm could not have been computed!",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_super {
+    use super::*;
+    use crate::problem::OptProblemKind;
+    use rand::distributions::Uniform;
+    use rand::{thread_rng, Rng};
+
+    fn random_instance(size: u32) -> Instance<crate::knapsack::DefaultItem<u32>, u32, u32> {
+        let mut rng = thread_rng();
+        let items: Vec<(u32, u32)> = (0..20)
+            .map(|_| {
+                (
+                    rng.sample(Uniform::new(0, 100)),
+                    rng.sample(Uniform::new(1, 100)),
+                )
+            })
+            .collect();
+        Instance::from((items, size))
+    }
+
+    #[test]
+    fn solving_by_dominance_dp_works() {
+        let instance = Instance::from((vec![(5, 4), (4, 4), (7, 5), (1, 1)], 13));
+        assert_eq!(3, undominated(&instance).len());
+        let solution = instance.run(DominanceDP);
+        assert_eq!(Some(17), solution.cost(&instance));
+    }
+
+    #[test]
+    fn random_validation_dominance_dp_alg() {
+        let instance = random_instance(thread_rng().sample(Uniform::new(100, 300)));
+        let dp_solution = instance.run(DominanceDP);
+        let ilp_solution = instance.solve_by_reduction(&BranchAndBound::default());
+        assert_eq!(ilp_solution.cost(&instance), dp_solution.cost(&instance));
+    }
+
+    #[test]
+    fn random_validation_periodicity_alg() {
+        let instance = random_instance(thread_rng().sample(Uniform::new(5000, 20000)));
+        let solution = instance.run(Periodicity);
+        assert_eq!(
+            instance.run(DominanceDP).cost(&instance),
+            solution.cost(&instance)
+        );
+        let weight: u32 = instance
+            .items()
+            .iter()
+            .zip(solution.as_solution().unwrap())
+            .map(|(item, count)| item.weight() * count)
+            .sum();
+        assert!(weight <= *instance.bag_size());
+    }
+
+    #[test]
+    fn solving_by_greedy_works() {
+        let instance = Instance::from((vec![(5, 4), (7, 5), (1, 1)], 13));
+        let solution = instance.run(Greedy);
+        assert_eq!(Some(vec![0, 2, 3]), solution.as_solution());
+    }
+
+    #[test]
+    fn random_validation_greedy_alg() {
+        let instance = random_instance(thread_rng().sample(Uniform::new(100, 300)));
+        let solution = instance.run(Greedy);
+        assert!(solution.is_solved());
+        assert!(Greedy.is_correct(&instance, &solution));
+    }
+}
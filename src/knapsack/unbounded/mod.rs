@@ -0,0 +1,128 @@
+use crate::knapsack::bounded::multiple;
+use crate::knapsack::{DefaultItem, Item};
+use crate::primitives::Numeric;
+use crate::problem::{OptProblemKind, SolutionKind};
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+pub use crate::knapsack::bounded::Solution;
+
+pub mod algorithms;
+pub mod reductions;
+
+/// Knapsack instance where every item may be packed any number of times.
+#[derive(Clone, Debug)]
+pub struct Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    items: Vec<I>,
+    size: W,
+    cost_type: PhantomData<C>,
+}
+
+impl<I, C, W> Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    pub fn new(items: Vec<I>, size: W) -> Self {
+        if items.iter().any(|item| *item.weight() <= W::zero()) {
+            panic!("Item weights must be positive!");
+        }
+        Instance {
+            items,
+            size,
+            cost_type: PhantomData,
+        }
+    }
+
+    pub fn items(&self) -> &[I] {
+        &self.items
+    }
+
+    pub fn number_of_items(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn bag_size(&self) -> &W {
+        &self.size
+    }
+}
+
+impl<I, C, W> Display for Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Unbounded knapsack instance\n   - size = {}\n   - items = {{ {} }}",
+            self.size,
+            self.items
+                .iter()
+                .map(|item| item.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+impl<I, C, W> OptProblemKind for Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    type Solution = Solution;
+    type Cost = C;
+}
+
+impl<I, C, W> SolutionKind<Instance<I, C, W>> for Solution
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn cost(&self, instance: &Instance<I, C, W>) -> Option<C> {
+        match self {
+            Solution::Infeasible | Solution::Failed(_) => None,
+            Solution::Solved { counts } => Some(
+                instance
+                    .items()
+                    .iter()
+                    .zip(counts.iter())
+                    .map(|(item, &count)| multiple(*item.cost(), count))
+                    .sum(),
+            ),
+        }
+    }
+}
+
+impl<T> From<(Vec<(T, T)>, T)> for Instance<DefaultItem<T>, T, T>
+where
+    T: Numeric,
+{
+    fn from(input: (Vec<(T, T)>, T)) -> Self {
+        let items = input.0.into_iter().map(DefaultItem::from).collect();
+        Instance::new(items, input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_instance_from_works() {
+        let instance = Instance::from((vec![(1, 2), (2, 3)], 5));
+        assert_eq!(2, instance.number_of_items());
+        let solution = Solution::Solved { counts: vec![1, 1] };
+        assert_eq!(Some(3), solution.cost(&instance));
+    }
+}
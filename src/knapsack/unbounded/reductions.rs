@@ -0,0 +1,90 @@
+use crate::knapsack::reductions::decode_solution;
+use crate::knapsack::unbounded::{Instance, Solution};
+use crate::knapsack::Item;
+use crate::primitives::Numeric;
+use crate::program::model::{LinearExpr, Sense, Variable};
+use crate::program::{LpSolution, MathProgram};
+use crate::reduction::Reduction;
+
+impl<I, C, W> Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    /// Integer variables bounded by the number of copies that fit into the bag.
+    fn item_variables(&self, program: &mut MathProgram) -> Vec<Variable> {
+        self.items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let copies = (self.size.into() / (*item.weight()).into()).floor();
+                program.add_integer(&format!("x_{}", index), Some(0.0), Some(copies.max(0.0)))
+            })
+            .collect()
+    }
+}
+
+impl<I, C, W> Reduction<MathProgram> for Instance<I, C, W>
+where
+    I: Item<C, W>,
+    C: Numeric,
+    W: Numeric,
+{
+    fn reduce_instance(&self) -> MathProgram {
+        let mut model = MathProgram::new("unbounded_knapsack", Sense::Maximize);
+        let vars = self.item_variables(&mut model);
+
+        model.set_objective(
+            self.items
+                .iter()
+                .zip(vars.iter())
+                .map(|(item, var)| (*item.cost()).into() * *var)
+                .sum::<LinearExpr>(),
+        );
+        model.add_constraint(
+            self.items
+                .iter()
+                .zip(vars.iter())
+                .map(|(item, var)| (*item.weight()).into() * *var)
+                .sum::<LinearExpr>()
+                .le(self.size.into()),
+        );
+
+        model
+    }
+
+    fn reduce_solution(&self, solution: &LpSolution) -> Solution {
+        decode_solution(solution, "unbounded knapsack", self.items.len(), |values| {
+            Solution::Solved {
+                counts: values
+                    .into_iter()
+                    .map(|value| value.max(0.0) as u32)
+                    .collect(),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_super {
+    use super::*;
+    use crate::problem::SolutionKind;
+    use crate::program::branch_and_bound::BranchAndBound;
+
+    #[test]
+    fn reduction_works() {
+        let instance = Instance::from((vec![(5.0, 4.0), (7.0, 5.0)], 13.0));
+        let program = instance.reduce_instance();
+        let var = program.variables().nth(1).unwrap();
+        assert_eq!(Some(2.0), program.variable(var).upper());
+        assert_eq!(1, program.number_of_constraints());
+    }
+
+    #[test]
+    fn solving_by_reduction_works() {
+        let instance = Instance::from((vec![(5.0, 4.0), (7.0, 5.0), (1.0, 1.0)], 13.0));
+        let solution = instance.solve_by_reduction(&BranchAndBound::default());
+        assert_eq!(Some(17.0), solution.cost(&instance));
+    }
+}
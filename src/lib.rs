@@ -4,6 +4,7 @@ pub mod primitives;
 pub mod problem;
 pub mod program;
 pub mod reduction;
+pub mod subset_sum;
 
 #[cfg(test)]
 mod tests {
@@ -1,6 +1,6 @@
 use std::fmt::Display;
 use std::iter::Sum;
-use std::ops::{Add, AddAssign};
+use std::ops::{Add, AddAssign, Sub};
 
 pub trait Zero {
     fn zero() -> Self;
@@ -10,6 +10,7 @@ pub trait Numeric:
     Add
     + AddAssign
     + Add<Output = Self>
+    + Sub<Output = Self>
     + PartialOrd
     + PartialEq
     + Sum
@@ -21,6 +22,47 @@ pub trait Numeric:
 {
 }
 
+/// `Send` with the `parallel` feature, implemented by every type without it.
+#[cfg(feature = "parallel")]
+pub trait MaybeSend: Send {}
+#[cfg(feature = "parallel")]
+impl<T: Send> MaybeSend for T {}
+#[cfg(not(feature = "parallel"))]
+pub trait MaybeSend {}
+#[cfg(not(feature = "parallel"))]
+impl<T> MaybeSend for T {}
+
+/// `Sync` with the `parallel` feature, implemented by every type without it.
+#[cfg(feature = "parallel")]
+pub trait MaybeSync: Sync {}
+#[cfg(feature = "parallel")]
+impl<T: Sync> MaybeSync for T {}
+#[cfg(not(feature = "parallel"))]
+pub trait MaybeSync {}
+#[cfg(not(feature = "parallel"))]
+impl<T> MaybeSync for T {}
+
+/// Addition that reports overflow instead of panicking or wrapping around.
+pub trait CheckedAdd: Sized {
+    fn checked_add(self, other: Self) -> Option<Self>;
+}
+
+impl CheckedAdd for f64 {
+    fn checked_add(self, other: f64) -> Option<f64> {
+        Some(self + other)
+    }
+}
+impl CheckedAdd for i32 {
+    fn checked_add(self, other: i32) -> Option<i32> {
+        i32::checked_add(self, other)
+    }
+}
+impl CheckedAdd for u32 {
+    fn checked_add(self, other: u32) -> Option<u32> {
+        u32::checked_add(self, other)
+    }
+}
+
 impl Numeric for f64 {}
 impl Numeric for i32 {}
 impl Numeric for u32 {}
@@ -0,0 +1,258 @@
+use crate::algorithm::Algorithm;
+use crate::program::simplex::{LinearProgram, Status, Tableau};
+use crate::program::{LpSolution, MathProgram, SolutionType, INTEGRALITY_TOLERANCE};
+
+const PRUNING_TOLERANCE: f64 = 1e-9;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NodeSelection {
+    /// Always continue with the open node of the best LP bound.
+    BestBound,
+    /// Always continue with the most recently created node.
+    DepthFirst,
+}
+
+/// Branch-and-bound solver for mixed-integer programs on top of the built-in
+/// simplex method. It branches on the most fractional integer variable and
+/// re-optimizes the child relaxations with the dual simplex method.
+pub struct BranchAndBound {
+    node_selection: NodeSelection,
+    node_limit: Option<usize>,
+}
+
+impl BranchAndBound {
+    pub fn new(node_selection: NodeSelection) -> Self {
+        BranchAndBound {
+            node_selection,
+            node_limit: None,
+        }
+    }
+
+    /// Stops after processing `limit` nodes. The best solution found so far is then
+    /// reported as [`SolutionType::SubOptimal`] together with its gap.
+    pub fn with_node_limit(mut self, limit: usize) -> Self {
+        self.node_limit = Some(limit);
+        self
+    }
+
+    fn next_node(&self, open: &mut Vec<Node>) -> Option<Node> {
+        match self.node_selection {
+            NodeSelection::DepthFirst => open.pop(),
+            NodeSelection::BestBound => {
+                let best = open
+                    .iter()
+                    .enumerate()
+                    .min_by(|(_, a), (_, b)| a.bound.partial_cmp(&b.bound).unwrap())
+                    .map(|(index, _)| index)?;
+                Some(open.swap_remove(best))
+            }
+        }
+    }
+}
+
+impl Default for BranchAndBound {
+    fn default() -> Self {
+        BranchAndBound::new(NodeSelection::BestBound)
+    }
+}
+
+struct Node {
+    tableau: Tableau<f64>,
+    bound: f64,
+}
+
+fn is_dominated(bound: f64, incumbent: &Option<(f64, Vec<f64>)>) -> bool {
+    match incumbent {
+        Some((value, _)) => bound >= value - PRUNING_TOLERANCE * (1.0 + value.abs()),
+        None => false,
+    }
+}
+
+fn most_fractional(program: &LinearProgram, values: &[f64]) -> Option<(usize, f64)> {
+    (0..program.number_of_variables())
+        .filter(|&column| program.is_integer(column))
+        .map(|column| {
+            let value = values[column];
+            (
+                column,
+                value,
+                (value - value.floor()).min(value.ceil() - value),
+            )
+        })
+        .filter(|(_, _, fractionality)| *fractionality > INTEGRALITY_TOLERANCE)
+        .max_by(|(_, _, a), (_, _, b)| a.partial_cmp(b).unwrap())
+        .map(|(column, value, _)| (column, value))
+}
+
+impl Algorithm<MathProgram> for BranchAndBound {
+    fn run(&self, instance: &MathProgram) -> LpSolution {
+        let program = LinearProgram::new(instance);
+        let n = program.number_of_variables();
+
+        let mut root: Tableau<f64> = Tableau::new(&program);
+        for column in (0..n).filter(|&column| program.is_integer(column)) {
+            let (lower, upper) = program.bounds(column);
+            let (lower, upper) = (lower.map(f64::ceil), upper.map(f64::floor));
+            if let (Some(lower), Some(upper)) = (lower, upper) {
+                if lower > upper {
+                    return LpSolution::Infeasible;
+                }
+            }
+            root.set_bounds(column, lower, upper);
+        }
+        match root.solve() {
+            Status::Optimal => {}
+            Status::Infeasible => return LpSolution::Infeasible,
+            Status::Unbounded => return LpSolution::Unbounded,
+            Status::IterationLimit => {
+                return LpSolution::Failed(String::from("Iteration limit reached at root node"))
+            }
+        }
+
+        let mut open = vec![Node {
+            bound: root.objective(),
+            tableau: root,
+        }];
+        let mut incumbent: Option<(f64, Vec<f64>)> = None;
+        let mut processed = 0;
+
+        while let Some(node) = self.next_node(&mut open) {
+            if is_dominated(node.bound, &incumbent) {
+                continue;
+            }
+            if matches!(self.node_limit, Some(limit) if processed >= limit) {
+                open.push(node);
+                break;
+            }
+            processed += 1;
+
+            let (column, value) = match most_fractional(&program, node.tableau.values()) {
+                Some(candidate) => candidate,
+                None => {
+                    let values = node.tableau.values()[..n]
+                        .iter()
+                        .enumerate()
+                        .map(|(column, value)| {
+                            if program.is_integer(column) {
+                                value.round()
+                            } else {
+                                *value
+                            }
+                        })
+                        .collect();
+                    incumbent = Some((node.bound, values));
+                    continue;
+                }
+            };
+
+            let (lower, upper) = node.tableau.bounds(column);
+            let children = [(lower, Some(value.floor())), (Some(value.ceil()), upper)];
+            for &(lower, upper) in children.iter() {
+                let mut tableau = node.tableau.clone();
+                tableau.set_bounds(column, lower, upper);
+                match tableau.reoptimize() {
+                    Status::Optimal => open.push(Node {
+                        bound: tableau.objective(),
+                        tableau,
+                    }),
+                    Status::Infeasible => {}
+                    _ => return LpSolution::Failed(String::from("Iteration limit reached")),
+                }
+            }
+        }
+
+        let best_bound = open
+            .iter()
+            .map(|node| node.bound)
+            .filter(|&bound| !is_dominated(bound, &incumbent))
+            .fold(None, |best: Option<f64>, bound| match best {
+                Some(best) if best <= bound => Some(best),
+                _ => Some(bound),
+            });
+
+        match (incumbent, best_bound) {
+            (Some((_, values)), None) => program.solution(&values, SolutionType::Optimal, None),
+            (Some((value, values)), Some(bound)) => {
+                let scale = program.objective_value(&values).abs();
+                let gap = (value - bound) / scale.max(PRUNING_TOLERANCE);
+                program.solution(&values, SolutionType::SubOptimal { gap: Some(gap) }, None)
+            }
+            (None, None) => LpSolution::Infeasible,
+            (None, Some(_)) => LpSolution::Failed(String::from(
+                "Node limit reached before a feasible solution was found",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_super {
+    use super::*;
+    use crate::program::model::{Sense, Variable};
+
+    fn integer_program() -> (MathProgram, Variable, Variable) {
+        let mut program = MathProgram::new("ip", Sense::Maximize);
+        let x = program.add_integer("x", Some(0.0), None);
+        let y = program.add_integer("y", Some(0.0), None);
+        program.set_objective(5.0 * x + 4.0 * y);
+        program.add_constraint((6.0 * x + 4.0 * y).le(24.0));
+        program.add_constraint((x + 2.0 * y).le(6.0));
+        program.add_constraint((3.0 * x + y).le(10.0));
+        (program, x, y)
+    }
+
+    fn assert_optimum(node_selection: NodeSelection) {
+        let (program, x, y) = integer_program();
+        let solution = BranchAndBound::new(node_selection).run(&program);
+        assert_eq!(Some(3.0), solution.value(x));
+        assert_eq!(Some(1.0), solution.value(y));
+        match solution {
+            LpSolution::Solved {
+                kind: SolutionType::Optimal,
+                value,
+                ..
+            } => assert_eq!(Some(19.0), value),
+            _ => panic!("Integer program was not solved to optimality"),
+        }
+    }
+
+    #[test]
+    fn best_bound_search_works() {
+        assert_optimum(NodeSelection::BestBound);
+    }
+
+    #[test]
+    fn depth_first_search_works() {
+        assert_optimum(NodeSelection::DepthFirst);
+    }
+
+    #[test]
+    fn infeasible_integer_program_is_detected() {
+        let mut program = MathProgram::new("ip", Sense::Maximize);
+        let x = program.add_integer("x", Some(0.0), None);
+        program.set_objective(x);
+        program.add_constraint((2.0 * x).ge(1.0));
+        program.add_constraint((2.0 * x).le(1.5));
+        let solution = BranchAndBound::default().run(&program);
+        assert!(matches!(solution, LpSolution::Infeasible));
+    }
+
+    #[test]
+    fn node_limit_reports_gap() {
+        let (program, _, _) = integer_program();
+        let solution = BranchAndBound::new(NodeSelection::DepthFirst)
+            .with_node_limit(2)
+            .run(&program);
+        match solution {
+            LpSolution::Solved {
+                kind: SolutionType::SubOptimal { gap: Some(gap) },
+                value: Some(value),
+                ..
+            } => {
+                assert_eq!(18.0, value);
+                assert!((gap - 1.0 / 18.0).abs() < 1e-6);
+            }
+            _ => panic!("Branch and bound did not stop at the node limit"),
+        }
+    }
+}
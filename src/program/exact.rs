@@ -0,0 +1,151 @@
+use crate::algorithm::Algorithm;
+use crate::primitives::Zero;
+use crate::program::simplex::{LinearProgram, Scalar, Status, Tableau};
+use crate::program::{DualSolution, LpSolution, MathProgram, SolutionType};
+use num_traits::ToPrimitive;
+
+pub use num_rational::BigRational;
+
+impl Zero for BigRational {
+    fn zero() -> Self {
+        num_traits::Zero::zero()
+    }
+}
+
+impl Scalar for BigRational {
+    fn from_f64(value: f64) -> Self {
+        BigRational::from_float(value).expect("Coefficients and bounds must be finite")
+    }
+
+    fn tolerance() -> Self {
+        num_traits::Zero::zero()
+    }
+
+    fn feasibility_tolerance() -> Self {
+        num_traits::Zero::zero()
+    }
+}
+
+fn to_f64(value: &BigRational) -> f64 {
+    match (value.numer().to_f64(), value.denom().to_f64()) {
+        (Some(numer), Some(denom)) => numer / denom,
+        _ => f64::NAN,
+    }
+}
+
+/// Solution of the LP relaxation of a [`MathProgram`] in exact rational arithmetic.
+/// Since every `f64` is a dyadic rational, the input data is taken over without error.
+pub enum ExactSolution {
+    Solved {
+        vars: Vec<BigRational>,
+        value: BigRational,
+        slacks: Vec<BigRational>,
+        duals: Box<DualSolution<BigRational>>,
+    },
+    Infeasible,
+    Unbounded,
+    Failed(String),
+}
+
+/// Simplex method in exact rational arithmetic, meant for verifying results of
+/// the floating point solvers on small programs. Like [`LpSolver::Simplex`](super::LpSolver::Simplex),
+/// it ignores integrality.
+pub struct ExactSimplex;
+
+impl ExactSimplex {
+    pub fn solve(&self, instance: &MathProgram) -> ExactSolution {
+        let program = LinearProgram::new(instance);
+        let mut tableau: Tableau<BigRational> = Tableau::new(&program);
+        match tableau.solve() {
+            Status::Optimal => {
+                let vars = tableau.values()[..program.number_of_variables()].to_vec();
+                ExactSolution::Solved {
+                    value: program.objective_value(&vars),
+                    slacks: program.slacks(&vars),
+                    duals: Box::new(program.dual_solution(&tableau)),
+                    vars,
+                }
+            }
+            Status::Infeasible => ExactSolution::Infeasible,
+            Status::Unbounded => ExactSolution::Unbounded,
+            Status::IterationLimit => {
+                ExactSolution::Failed(String::from("Iteration limit reached"))
+            }
+        }
+    }
+}
+
+impl From<&ExactSolution> for LpSolution {
+    fn from(solution: &ExactSolution) -> Self {
+        match solution {
+            ExactSolution::Solved {
+                vars,
+                value,
+                slacks,
+                duals,
+            } => LpSolution::Solved {
+                vars: vars.iter().map(to_f64).collect(),
+                kind: SolutionType::Optimal,
+                value: Some(to_f64(value)),
+                slacks: slacks.iter().map(to_f64).collect(),
+                duals: Some(duals.map(to_f64)),
+            },
+            ExactSolution::Infeasible => LpSolution::Infeasible,
+            ExactSolution::Unbounded => LpSolution::Unbounded,
+            ExactSolution::Failed(msg) => LpSolution::Failed(msg.clone()),
+        }
+    }
+}
+
+impl Algorithm<MathProgram> for ExactSimplex {
+    fn run(&self, instance: &MathProgram) -> LpSolution {
+        LpSolution::from(&self.solve(instance))
+    }
+}
+
+#[cfg(test)]
+mod test_super {
+    use super::*;
+    use crate::program::model::Sense;
+
+    #[test]
+    fn solving_lp_exactly_works() {
+        let mut program = MathProgram::new("lp", Sense::Maximize);
+        let x = program.add_continuous("x", Some(0.0), None);
+        let y = program.add_continuous("y", Some(0.0), None);
+        program.set_objective(x + 2.0 * y);
+        program.add_constraint((3.0 * x + 3.0 * y).le(1.0));
+        program.add_constraint((x - y).ge(0.0));
+
+        match ExactSimplex.solve(&program) {
+            ExactSolution::Solved {
+                vars, value, duals, ..
+            } => {
+                let sixth = BigRational::new(1.into(), 6.into());
+                assert_eq!(sixth, vars[x.index()]);
+                assert_eq!(sixth, vars[y.index()]);
+                assert_eq!(BigRational::new(1.into(), 2.into()), value);
+                assert_eq!(BigRational::new(1.into(), 2.into()), duals.dual(0));
+                assert_eq!(BigRational::new((-1).into(), 2.into()), duals.dual(1));
+                assert_eq!(
+                    (Some(BigRational::from_integer(0.into())), None),
+                    duals.rhs_range(0)
+                );
+            }
+            _ => panic!("Program was not solved"),
+        }
+    }
+
+    #[test]
+    fn infeasible_lp_is_detected_exactly() {
+        let mut program = MathProgram::new("lp", Sense::Minimize);
+        let x = program.add_continuous("x", Some(0.0), Some(1.0));
+        program.set_objective(x);
+        program.add_constraint((3.0 * x).ge(3.0 + 1e-12));
+
+        assert!(matches!(
+            ExactSimplex.solve(&program),
+            ExactSolution::Infeasible
+        ));
+    }
+}
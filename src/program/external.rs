@@ -0,0 +1,197 @@
+use crate::program::model::{Comparison, LinearExpr, Sense, VariableKind};
+use crate::program::{LpSolution, MathProgram, SolutionType};
+use lp_modeler::dsl::*;
+use lp_modeler::solvers::{SolverTrait, Status};
+
+fn to_expression(expr: &LinearExpr, variables: &[LpExpression]) -> LpExpression {
+    let constant: LpExpression = (expr.constant() as f32).into();
+    expr.terms()
+        .iter()
+        .fold(constant, |sum, (var, coefficient)| {
+            sum + *coefficient as f32 * &variables[var.index()]
+        })
+}
+
+/// Fails if a number of the program would be rounded by the `f32` of lp-modeler.
+fn check_precision(program: &MathProgram) -> Result<(), String> {
+    let expressions = std::iter::once(program.objective())
+        .chain(program.constraints().iter().map(|c| c.expression()));
+    let mut numbers = expressions
+        .flat_map(|expr| {
+            let coefficients = expr.terms().iter().map(|(_, coefficient)| *coefficient);
+            coefficients.chain(std::iter::once(expr.constant()))
+        })
+        .chain(program.constraints().iter().map(|c| c.rhs()))
+        .chain(program.variables().flat_map(|var| {
+            let info = program.variable(var);
+            info.lower().into_iter().chain(info.upper())
+        }));
+    match numbers.find(|&value| f64::from(value as f32) != value) {
+        Some(value) => Err(format!(
+            "Value {} cannot be exported to lp-modeler without rounding",
+            value
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Numbers are converted to `f32`, which rounds integers above `2^24`.
+impl From<&MathProgram> for LpProblem {
+    fn from(program: &MathProgram) -> LpProblem {
+        let objective = match program.sense() {
+            Sense::Minimize => LpObjective::Minimize,
+            Sense::Maximize => LpObjective::Maximize,
+        };
+        let mut problem = LpProblem::new("math_program", objective);
+
+        let variables: Vec<LpExpression> = program
+            .variables()
+            .map(|var| {
+                let info = program.variable(var);
+                match info.kind() {
+                    VariableKind::Binary => LpBinary::new(info.name()).into(),
+                    VariableKind::Integer => {
+                        let mut integer = LpInteger::new(info.name());
+                        // An unbounded variable would get the default lower bound 0.
+                        let lower = info.lower().unwrap_or(f64::NEG_INFINITY);
+                        integer = integer.lower_bound(lower as f32);
+                        if let Some(upper) = info.upper() {
+                            integer = integer.upper_bound(upper as f32);
+                        }
+                        integer.into()
+                    }
+                    VariableKind::Continuous => {
+                        let mut continuous = LpContinuous::new(info.name());
+                        // An unbounded variable would get the default lower bound 0.
+                        let lower = info.lower().unwrap_or(f64::NEG_INFINITY);
+                        continuous = continuous.lower_bound(lower as f32);
+                        if let Some(upper) = info.upper() {
+                            continuous = continuous.upper_bound(upper as f32);
+                        }
+                        continuous.into()
+                    }
+                }
+            })
+            .collect();
+
+        problem += to_expression(program.objective(), &variables);
+        for constraint in program.constraints() {
+            let expression = to_expression(constraint.expression(), &variables);
+            let rhs = constraint.rhs() as f32;
+            problem += match constraint.comparison() {
+                Comparison::LessOrEqual => expression.le(rhs),
+                Comparison::GreaterOrEqual => expression.ge(rhs),
+                Comparison::Equal => expression.equal(rhs),
+            };
+        }
+        problem
+    }
+}
+
+pub(crate) fn solve<S>(program: &MathProgram, solver: &S) -> LpSolution
+where
+    S: SolverTrait<P = LpProblem>,
+{
+    if let Err(msg) = check_precision(program) {
+        return LpSolution::Failed(msg);
+    }
+    let problem = LpProblem::from(program);
+    let solution = match solver.run(&problem) {
+        Ok(solution) => solution,
+        Err(msg) => return LpSolution::Failed(msg),
+    };
+    let kind = match solution.status {
+        Status::Optimal => SolutionType::Optimal,
+        Status::SubOptimal => SolutionType::SubOptimal { gap: None },
+        Status::Infeasible => return LpSolution::Infeasible,
+        Status::Unbounded => return LpSolution::Unbounded,
+        Status::NotSolved => return LpSolution::Failed(String::from("Not solved")),
+    };
+    let vars: Vec<f64> = program
+        .variables()
+        .map(|var| {
+            solution
+                .results
+                .get(program.variable(var).name())
+                .map_or(0.0, |value| f64::from(*value))
+        })
+        .collect();
+    LpSolution::Solved {
+        value: Some(program.objective().evaluate(&vars)),
+        slacks: program
+            .constraints()
+            .iter()
+            .map(|constraint| constraint.rhs() - constraint.expression().evaluate(&vars))
+            .collect(),
+        vars,
+        kind,
+        duals: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::Algorithm;
+    use crate::problem::SolutionKind;
+    use crate::program::LpSolver;
+    use lp_modeler::format::lp_format::LpFileFormat;
+
+    fn program() -> MathProgram {
+        let mut program = MathProgram::new("knapsack", Sense::Maximize);
+        let x = program.add_binary("x");
+        let y = program.add_integer("y", Some(0.0), Some(3.0));
+        let z = program.add_continuous("z", Some(0.0), None);
+        program.set_objective(2.0 * x + 3.0 * y + LinearExpr::from(z));
+        program.add_constraint((3.0 * x + 2.0 * y + z).le(7.0));
+        program.add_constraint((x + z).ge(1.0));
+        program
+    }
+
+    #[test]
+    fn exporting_to_lp_modeler_works() {
+        let problem = LpProblem::from(&program());
+        assert_eq!(3, problem.variables().len());
+    }
+
+    fn negative_program() -> MathProgram {
+        let mut program = MathProgram::new("negative", Sense::Minimize);
+        let x = program.add_integer("x", None, Some(5.0));
+        let y = program.add_continuous("y", None, None);
+        program.set_objective(x + y);
+        program.add_constraint(LinearExpr::from(x).ge(-2.5));
+        program.add_constraint((y - x).ge(-1.5));
+        program
+    }
+
+    #[test]
+    fn exporting_unbounded_variables_works() {
+        let text = LpProblem::from(&negative_program()).to_lp_file_format();
+        assert!(text.contains("-inf <= x <= 5"));
+        assert!(text.contains("-inf <= y"));
+    }
+
+    #[test]
+    fn solving_imprecise_program_fails() {
+        let mut program = program();
+        let x = program.variables().next().unwrap();
+        program.add_constraint(LinearExpr::from(x).le(16_777_217.0));
+        assert!(matches!(LpSolver::CBC.run(&program), LpSolution::Failed(_)));
+        assert!(matches!(
+            LpSolver::GLPK.run(&program),
+            LpSolution::Failed(_)
+        ));
+    }
+
+    #[test]
+    #[ignore = "requires the cbc binary"]
+    fn solving_by_cbc_works() {
+        let program = program();
+        let solution = LpSolver::CBC.run(&program);
+        assert_eq!(Some(10.0), solution.cost(&program));
+
+        let program = negative_program();
+        let solution = LpSolver::CBC.run(&program);
+        assert_eq!(Some(-5.5), solution.cost(&program));
+    }
+}
@@ -0,0 +1,575 @@
+use super::{column_names, row_name, ProgramBuilder};
+use crate::program::model::{Comparison, Constraint, LinearExpr, Sense, Variable, VariableKind};
+use crate::program::MathProgram;
+
+const OBJECTIVE_ROW: &str = "obj";
+const LINE_LENGTH: usize = 250;
+const NAME_SYMBOLS: &str = "!\"#$%&()/,.;?@_`'{}|~";
+
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || NAME_SYMBOLS.contains(c)
+}
+
+fn admits(name: &str) -> bool {
+    match name.chars().next() {
+        Some(first) => {
+            !first.is_ascii_digit()
+                && first != '.'
+                && name.len() <= 255
+                && name.chars().all(is_name_char)
+                && section(name).is_none()
+                && !is_infinity(name)
+                && !name.eq_ignore_ascii_case("free")
+        }
+        None => false,
+    }
+}
+
+fn number(value: f64) -> String {
+    value.to_string()
+}
+
+/// Appends `terms` to `out`, breaking lines that grow longer than [`LINE_LENGTH`].
+fn write_terms(out: &mut String, terms: &[(String, f64)]) {
+    let mut length = 0;
+    for (index, (name, coefficient)) in terms.iter().enumerate() {
+        let term = match (index, *coefficient < 0.0) {
+            (0, _) => format!(" {} {}", number(*coefficient), name),
+            (_, true) => format!(" - {} {}", number(-coefficient), name),
+            (_, false) => format!(" + {} {}", number(*coefficient), name),
+        };
+        if length + term.len() > LINE_LENGTH {
+            out.push_str("\n  ");
+            length = 0;
+        }
+        length += term.len();
+        out.push_str(&term);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Section {
+    Objective(Sense),
+    Constraints,
+    Bounds,
+    Generals,
+    Binaries,
+    End,
+}
+
+/// Section started by a line beginning with `line`, together with the number of
+/// words the keyword takes up.
+fn section(line: &str) -> Option<(Section, usize)> {
+    let mut words = line.split_whitespace().map(str::to_lowercase);
+    let first = words.next()?;
+    let section = match first.as_str() {
+        "maximize" | "maximise" | "maximum" | "max" => Section::Objective(Sense::Maximize),
+        "minimize" | "minimise" | "minimum" | "min" => Section::Objective(Sense::Minimize),
+        "subject" | "such" => {
+            let second = words.next();
+            return match second.as_deref() {
+                Some("to") | Some("that") => Some((Section::Constraints, 2)),
+                _ => None,
+            };
+        }
+        "st" | "s.t." | "st." => Section::Constraints,
+        "bounds" | "bound" => Section::Bounds,
+        "general" | "generals" | "gen" => Section::Generals,
+        "binary" | "binaries" | "bin" => Section::Binaries,
+        "end" => Section::End,
+        _ => return None,
+    };
+    Some((section, 1))
+}
+
+fn is_infinity(name: &str) -> bool {
+    name.eq_ignore_ascii_case("inf") || name.eq_ignore_ascii_case("infinity")
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Number(f64),
+    Name(String),
+    Plus,
+    Minus,
+    Colon,
+    Compare(Comparison),
+}
+
+fn tokenize(text: &str, tokens: &mut Vec<Token>) -> Result<(), String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+        i += 1;
+        let token = match c {
+            _ if c.is_whitespace() => continue,
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            ':' => Token::Colon,
+            '<' | '>' | '=' => {
+                if i < chars.len() && matches!(chars[i], '<' | '>' | '=') {
+                    i += 1;
+                }
+                let symbol: String = chars[start..i].iter().collect();
+                Token::Compare(match symbol.as_str() {
+                    "<" | "<=" | "=<" => Comparison::LessOrEqual,
+                    ">" | ">=" | "=>" => Comparison::GreaterOrEqual,
+                    "=" => Comparison::Equal,
+                    _ => return Err(format!("Invalid operator '{}'", symbol)),
+                })
+            }
+            _ if c.is_ascii_digit() || c == '.' => {
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                if i < chars.len() && (chars[i] == 'e' || chars[i] == 'E') {
+                    let mut j = i + 1;
+                    if j < chars.len() && (chars[j] == '+' || chars[j] == '-') {
+                        j += 1;
+                    }
+                    if j < chars.len() && chars[j].is_ascii_digit() {
+                        i = j;
+                        while i < chars.len() && chars[i].is_ascii_digit() {
+                            i += 1;
+                        }
+                    }
+                }
+                let literal: String = chars[start..i].iter().collect();
+                Token::Number(
+                    literal
+                        .parse()
+                        .map_err(|_| format!("Invalid number '{}'", literal))?,
+                )
+            }
+            _ if is_name_char(c) => {
+                while i < chars.len() && is_name_char(chars[i]) {
+                    i += 1;
+                }
+                Token::Name(chars[start..i].iter().collect())
+            }
+            _ => return Err(format!("Unexpected character '{}'", c)),
+        };
+        tokens.push(token);
+    }
+    Ok(())
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    position: usize,
+    builder: &'a mut ProgramBuilder,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self, offset: usize) -> Option<&'a Token> {
+        self.tokens.get(self.position + offset)
+    }
+
+    fn next(&mut self) -> Option<&'a Token> {
+        let token = self.peek(0);
+        self.position += 1;
+        token
+    }
+
+    fn is_done(&self) -> bool {
+        self.position >= self.tokens.len()
+    }
+
+    fn skip_label(&mut self) {
+        if let (Some(Token::Name(_)), Some(Token::Colon)) = (self.peek(0), self.peek(1)) {
+            self.position += 2;
+        }
+    }
+
+    fn sign(&mut self) -> f64 {
+        let mut sign = 1.0;
+        while let Some(token) = self.peek(0) {
+            match token {
+                Token::Plus => {}
+                Token::Minus => sign = -sign,
+                _ => break,
+            }
+            self.position += 1;
+        }
+        sign
+    }
+
+    /// Parses terms up to the next comparison operator, label or the end of the section.
+    fn expression(&mut self) -> Result<LinearExpr, String> {
+        let mut expression = LinearExpr::new();
+        while let Some(token) = self.peek(0) {
+            if let (Token::Compare(_), _) | (Token::Name(_), Some(Token::Colon)) =
+                (token, self.peek(1))
+            {
+                break;
+            }
+            let sign = self.sign();
+            match (self.next(), self.peek(0)) {
+                (Some(Token::Number(coefficient)), Some(Token::Name(name)))
+                    if !is_infinity(name) =>
+                {
+                    self.position += 1;
+                    expression += sign * coefficient * self.builder.variable(name);
+                }
+                (Some(Token::Number(constant)), _) => expression += sign * constant,
+                (Some(Token::Name(name)), _) if !is_infinity(name) => {
+                    expression += sign * self.builder.variable(name)
+                }
+                (token, _) => return Err(format!("Unexpected token {:?} in expression", token)),
+            }
+        }
+        Ok(expression)
+    }
+
+    fn comparison(&mut self) -> Result<Comparison, String> {
+        match self.next() {
+            Some(Token::Compare(comparison)) => Ok(*comparison),
+            token => Err(format!("Expected comparison, found {:?}", token)),
+        }
+    }
+
+    /// Parses a signed constant, where infinite values are `None`.
+    fn value(&mut self) -> Result<Option<f64>, String> {
+        let sign = self.sign();
+        match self.next() {
+            Some(Token::Number(value)) => Ok(Some(sign * value)),
+            Some(Token::Name(name)) if is_infinity(name) => Ok(None),
+            token => Err(format!("Expected number, found {:?}", token)),
+        }
+    }
+
+    /// Parses constraints `expr <comparison> constant`, where a constant left-hand
+    /// side like in `-1 <= expr <= 1` makes it a ranged constraint.
+    fn constraints(&mut self) -> Result<Vec<Constraint>, String> {
+        let mut constraints = Vec::new();
+        while !self.is_done() {
+            self.skip_label();
+            let left = self.expression()?;
+            let comparison = self.comparison()?;
+            if left.terms().is_empty() {
+                let middle = self.expression()?;
+                constraints.push(constraint(left, comparison, middle.clone()));
+                if let Some(Token::Compare(_)) = self.peek(0) {
+                    let second = self.comparison()?;
+                    let right = self.finite_value()?;
+                    constraints.push(constraint(middle, second, LinearExpr::from(right)));
+                }
+            } else {
+                let right = self.finite_value()?;
+                constraints.push(constraint(left, comparison, LinearExpr::from(right)));
+            }
+        }
+        Ok(constraints)
+    }
+
+    fn finite_value(&mut self) -> Result<f64, String> {
+        self.value()?
+            .ok_or_else(|| String::from("Right-hand sides must be finite"))
+    }
+
+    fn bounds(&mut self) -> Result<(), String> {
+        while !self.is_done() {
+            if let (Some(Token::Name(name)), Some(Token::Name(free))) = (self.peek(0), self.peek(1))
+            {
+                if free.eq_ignore_ascii_case("free") {
+                    self.position += 2;
+                    let var = self.builder.variable(name);
+                    let info = self.builder.info_mut(var);
+                    info.lower = None;
+                    info.upper = None;
+                    continue;
+                }
+            }
+            match self.peek(0) {
+                Some(Token::Name(name)) if !is_infinity(name) => {
+                    self.position += 1;
+                    let var = self.builder.variable(name);
+                    let comparison = self.comparison()?;
+                    let value = self.value()?;
+                    self.bound(var, comparison, value, false);
+                }
+                _ => {
+                    let value = self.value()?;
+                    let comparison = self.comparison()?;
+                    let var = match self.next() {
+                        Some(Token::Name(name)) => self.builder.variable(name),
+                        token => return Err(format!("Expected variable, found {:?}", token)),
+                    };
+                    self.bound(var, comparison, value, true);
+                    if let Some(Token::Compare(_)) = self.peek(0) {
+                        let comparison = self.comparison()?;
+                        let value = self.value()?;
+                        self.bound(var, comparison, value, false);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies `var <comparison> value`, or `value <comparison> var` if `reversed`.
+    fn bound(&mut self, var: Variable, comparison: Comparison, value: Option<f64>, reversed: bool) {
+        let info = self.builder.info_mut(var);
+        match (comparison, reversed) {
+            (Comparison::Equal, _) => {
+                info.lower = value;
+                info.upper = value;
+            }
+            (Comparison::LessOrEqual, false) | (Comparison::GreaterOrEqual, true) => {
+                info.upper = value
+            }
+            (Comparison::GreaterOrEqual, false) | (Comparison::LessOrEqual, true) => {
+                info.lower = value
+            }
+        }
+    }
+
+    fn declarations(&mut self, kind: VariableKind) -> Result<(), String> {
+        while let Some(token) = self.next() {
+            let var = match token {
+                Token::Name(name) => self.builder.variable(name),
+                token => return Err(format!("Expected variable, found {:?}", token)),
+            };
+            let info = self.builder.info_mut(var);
+            info.kind = kind;
+            if kind == VariableKind::Binary {
+                info.lower = Some(0.0);
+                info.upper = Some(1.0);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn constraint(left: LinearExpr, comparison: Comparison, right: LinearExpr) -> Constraint {
+    match comparison {
+        Comparison::LessOrEqual => left.le(right),
+        Comparison::GreaterOrEqual => left.ge(right),
+        Comparison::Equal => left.equal(right),
+    }
+}
+
+impl MathProgram {
+    /// Writes the program in CPLEX LP format. Every column appears in the objective,
+    /// if need be with a zero coefficient, so that reading the file back yields the
+    /// variables in the same order.
+    pub fn to_cplex_lp(&self) -> String {
+        let columns = column_names(self, admits);
+        let mut out = format!("\\ Problem name: {}\n", self.name());
+        out.push_str(match self.sense() {
+            Sense::Minimize => "Minimize\n",
+            Sense::Maximize => "Maximize\n",
+        });
+        out.push(' ');
+        out.push_str(OBJECTIVE_ROW);
+        out.push(':');
+        let objective: Vec<(String, f64)> = self
+            .variables()
+            .map(|var| {
+                let coefficient = self.objective().coefficient(var);
+                (columns[var.index()].clone(), coefficient)
+            })
+            .collect();
+        write_terms(&mut out, &objective);
+        let constant = self.objective().constant();
+        if constant != 0.0 {
+            let sign = if constant < 0.0 { '-' } else { '+' };
+            out.push_str(&format!(" {} {}", sign, number(constant.abs())));
+        }
+        out.push('\n');
+
+        out.push_str("Subject To\n");
+        for (index, constraint) in self.constraints().iter().enumerate() {
+            out.push_str(&format!(" {}:", row_name(index)));
+            let mut terms: Vec<(String, f64)> = constraint
+                .expression()
+                .terms()
+                .iter()
+                .map(|(var, coefficient)| (columns[var.index()].clone(), *coefficient))
+                .collect();
+            if terms.is_empty() {
+                terms.extend(columns.first().map(|name| (name.clone(), 0.0)));
+            }
+            write_terms(&mut out, &terms);
+            let comparison = match constraint.comparison() {
+                Comparison::LessOrEqual => "<=",
+                Comparison::GreaterOrEqual => ">=",
+                Comparison::Equal => "=",
+            };
+            out.push_str(&format!(" {} {}\n", comparison, number(constraint.rhs())));
+        }
+
+        let mut bounds = String::new();
+        let mut generals = String::new();
+        let mut binaries = String::new();
+        for (var, name) in self.variables().zip(columns.iter()) {
+            let info = self.variable(var);
+            let binary = info.kind() == VariableKind::Binary
+                && info.lower() == Some(0.0)
+                && info.upper() == Some(1.0);
+            if binary {
+                binaries.push_str(&format!(" {}\n", name));
+                continue;
+            }
+            if info.kind().is_integral() {
+                generals.push_str(&format!(" {}\n", name));
+            }
+            let bound = match (info.lower(), info.upper()) {
+                (Some(0.0), None) => continue,
+                (None, None) => format!(" {} free\n", name),
+                (Some(lower), Some(upper)) if lower == upper => {
+                    format!(" {} = {}\n", name, number(lower))
+                }
+                (Some(lower), None) => format!(" {} >= {}\n", name, number(lower)),
+                (lower, Some(upper)) => format!(
+                    " {} <= {} <= {}\n",
+                    lower.map_or(String::from("-inf"), number),
+                    name,
+                    number(upper)
+                ),
+            };
+            bounds.push_str(&bound);
+        }
+        for (header, section) in &[
+            ("Bounds", bounds),
+            ("Generals", generals),
+            ("Binaries", binaries),
+        ] {
+            if !section.is_empty() {
+                out.push_str(header);
+                out.push('\n');
+                out.push_str(section);
+            }
+        }
+        out.push_str("End\n");
+        out
+    }
+
+    /// Parses a program in CPLEX LP format. Quadratic terms, semi-continuous
+    /// variables and SOS constraints are not supported.
+    pub fn from_cplex_lp(text: &str) -> Result<MathProgram, String> {
+        let mut name = "";
+        let mut sections: Vec<(Section, Vec<Token>)> = Vec::new();
+        for line in text.lines() {
+            if let Some(comment) = line.trim_start().strip_prefix('\\') {
+                if let Some(problem) = comment.trim_start().strip_prefix("Problem name:") {
+                    name = problem.trim();
+                }
+            }
+            let line = line.split('\\').next().unwrap_or("");
+            let rest = match section(line) {
+                Some((section, words)) => {
+                    sections.push((section, Vec::new()));
+                    line.split_whitespace()
+                        .skip(words)
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                }
+                None => String::from(line),
+            };
+            match sections.last_mut() {
+                Some((_, tokens)) => tokenize(&rest, tokens)?,
+                None if rest.trim().is_empty() => {}
+                None => return Err(String::from("Expected objective sense")),
+            }
+        }
+
+        let mut builder = ProgramBuilder::default();
+        let mut sense = None;
+        let mut objective = LinearExpr::new();
+        let mut constraints = Vec::new();
+        for (section, tokens) in &sections {
+            let mut parser = Parser {
+                tokens,
+                position: 0,
+                builder: &mut builder,
+            };
+            match section {
+                Section::Objective(objective_sense) => {
+                    if sense.replace(*objective_sense).is_some() {
+                        return Err(String::from("Multiple objectives"));
+                    }
+                    parser.skip_label();
+                    objective = parser.expression()?;
+                    if !parser.is_done() {
+                        return Err(String::from("Comparison in objective"));
+                    }
+                }
+                Section::Constraints => constraints.extend(parser.constraints()?),
+                Section::Bounds => parser.bounds()?,
+                Section::Generals => parser.declarations(VariableKind::Integer)?,
+                Section::Binaries => parser.declarations(VariableKind::Binary)?,
+                Section::End => break,
+            }
+        }
+        let sense = sense.ok_or_else(|| String::from("Expected objective sense"))?;
+        Ok(builder.build(name, sense, objective, constraints))
+    }
+}
+
+#[cfg(test)]
+mod test_super {
+    use super::*;
+    use crate::algorithm::Algorithm;
+    use crate::program::branch_and_bound::BranchAndBound;
+    use crate::program::LpSolution;
+
+    #[test]
+    fn writing_and_reading_lp_works() {
+        let mut program = MathProgram::new("mixed", Sense::Maximize);
+        let x = program.add_binary("x");
+        let y = program.add_integer("y", Some(-2.0), Some(3.5));
+        let z = program.add_continuous("z", None, None);
+        let w = program.add_continuous("w", Some(0.0), None);
+        program.set_objective(3.0 * x + y - 0.5 * z + 1.0);
+        program.add_constraint((x + y + z).le(4.0));
+        program.add_constraint((z - 2.0 * x).ge(-1.0));
+        program.add_constraint((y + w).equal(2.0));
+
+        let text = program.to_cplex_lp();
+        let parsed = MathProgram::from_cplex_lp(&text).unwrap();
+        assert_eq!(text, parsed.to_cplex_lp());
+        assert_eq!("mixed", parsed.name());
+        assert_eq!(Sense::Maximize, parsed.sense());
+        assert_eq!("w", parsed.variable(w).name());
+        assert_eq!(VariableKind::Binary, parsed.variable(x).kind());
+        assert_eq!(Some(3.5), parsed.variable(y).upper());
+        assert_eq!(None, parsed.variable(z).lower());
+        assert_eq!(1.0, parsed.objective().constant());
+        assert_eq!(3, parsed.number_of_constraints());
+    }
+
+    #[test]
+    fn reading_lp_works() {
+        let text = "\\ a small integer program
+            MAXIMIZE
+              value: 5 x + 4y
+            subject to
+              c1: 6 x + 4 y <= 24
+              x + 2 y
+                <= 6
+              -1e1 <= -3 x - y
+            Bounds
+              0 <= x <= 1e+1
+              y >= 0
+            General
+              x y
+            End";
+        let program = MathProgram::from_cplex_lp(text).unwrap();
+        assert_eq!(2, program.number_of_variables());
+        assert_eq!(3, program.number_of_constraints());
+        match BranchAndBound::default().run(&program) {
+            LpSolution::Solved { value, .. } => assert_eq!(Some(19.0), value),
+            _ => panic!("Parsed program was not solved"),
+        }
+    }
+
+    #[test]
+    fn invalid_lp_is_rejected() {
+        assert!(MathProgram::from_cplex_lp("Subject To\n x <= 1\nEnd").is_err());
+        assert!(MathProgram::from_cplex_lp("Minimize\n x + [ x ^ 2 ]\nEnd").is_err());
+        assert!(MathProgram::from_cplex_lp("Minimize\n x\nSubject To\n x + y\nEnd").is_err());
+    }
+}
@@ -0,0 +1,84 @@
+//! Reading and writing [`MathProgram`]s in the MPS and CPLEX LP file formats.
+//!
+//! Constraints carry no names in a [`MathProgram`], so rows are written as `c0`, `c1`, ...
+//! and row names are dropped when reading. Variables keep their names unless a name
+//! is not admissible in the target format, in which case all columns are written as
+//! `x0`, `x1`, ... instead.
+
+use crate::program::model::{Constraint, LinearExpr, Sense, Variable, VariableInfo, VariableKind};
+use crate::program::MathProgram;
+use std::collections::{HashMap, HashSet};
+
+mod lp;
+mod mps;
+
+pub use mps::MpsFormat;
+
+fn column_names<F: Fn(&str) -> bool>(program: &MathProgram, admissible: F) -> Vec<String> {
+    let names: Vec<&str> = program
+        .variables()
+        .map(|var| program.variable(var).name())
+        .collect();
+    let unique = names.iter().collect::<HashSet<_>>().len() == names.len();
+    if unique && names.iter().all(|name| admissible(name)) {
+        names.into_iter().map(String::from).collect()
+    } else {
+        (0..names.len())
+            .map(|index| format!("x{}", index))
+            .collect()
+    }
+}
+
+fn row_name(index: usize) -> String {
+    format!("c{}", index)
+}
+
+/// Collects the variables of a program while parsing, in order of first appearance.
+#[derive(Default)]
+struct ProgramBuilder {
+    variables: Vec<VariableInfo>,
+    indices: HashMap<String, usize>,
+}
+
+impl ProgramBuilder {
+    fn get(&self, name: &str) -> Option<Variable> {
+        self.indices.get(name).map(|&index| Variable(index))
+    }
+
+    /// Returns the variable called `name`, adding a continuous variable with the
+    /// default bounds `[0, inf)` if there is none yet.
+    fn variable(&mut self, name: &str) -> Variable {
+        if let Some(var) = self.get(name) {
+            return var;
+        }
+        self.variables.push(VariableInfo {
+            name: String::from(name),
+            kind: VariableKind::Continuous,
+            lower: Some(0.0),
+            upper: None,
+        });
+        self.indices
+            .insert(String::from(name), self.variables.len() - 1);
+        Variable(self.variables.len() - 1)
+    }
+
+    fn info_mut(&mut self, var: Variable) -> &mut VariableInfo {
+        &mut self.variables[var.index()]
+    }
+
+    fn build(
+        self,
+        name: &str,
+        sense: Sense,
+        objective: LinearExpr,
+        constraints: Vec<Constraint>,
+    ) -> MathProgram {
+        MathProgram {
+            name: String::from(name),
+            sense,
+            objective: objective.simplified(),
+            variables: self.variables,
+            constraints,
+        }
+    }
+}
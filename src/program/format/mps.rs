@@ -0,0 +1,530 @@
+use super::{column_names, row_name, ProgramBuilder};
+use crate::program::model::{Comparison, LinearExpr, Sense, VariableKind};
+use crate::program::MathProgram;
+use std::collections::HashMap;
+
+const OBJECTIVE_ROW: &str = "obj";
+const FIXED_FIELD_STARTS: [usize; 6] = [1, 4, 14, 24, 39, 49];
+const FIXED_NUMBER_WIDTH: usize = 12;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MpsFormat {
+    /// Fields start at fixed columns and names have at most 8 characters.
+    Fixed,
+    /// Fields are separated by whitespace.
+    Free,
+}
+
+impl MpsFormat {
+    fn admits(&self, name: &str) -> bool {
+        let length = match self {
+            MpsFormat::Fixed => 8,
+            MpsFormat::Free => usize::MAX,
+        };
+        !name.is_empty()
+            && name.len() <= length
+            && !name.starts_with('$')
+            && !name.starts_with('*')
+            && !name.contains(char::is_whitespace)
+    }
+
+    fn line(&self, fields: &[&str]) -> String {
+        let mut line = String::new();
+        match self {
+            MpsFormat::Fixed => {
+                for (field, &start) in fields.iter().zip(FIXED_FIELD_STARTS.iter()) {
+                    if field.is_empty() {
+                        continue;
+                    }
+                    if line.len() >= start {
+                        line.push(' ');
+                    }
+                    while line.len() < start {
+                        line.push(' ');
+                    }
+                    line.push_str(field);
+                }
+            }
+            MpsFormat::Free => {
+                for field in fields.iter().filter(|field| !field.is_empty()) {
+                    line.push(' ');
+                    line.push_str(field);
+                }
+            }
+        }
+        line.push('\n');
+        line
+    }
+
+    /// Writes a number such that it fits into a numeric field of the fixed format,
+    /// which has 12 characters. Numbers that are too long are written in scientific
+    /// notation with the largest precision that fits, dropping digits if necessary.
+    fn number(&self, value: f64) -> String {
+        let text = value.to_string();
+        if *self == MpsFormat::Free || text.len() <= FIXED_NUMBER_WIDTH {
+            return text;
+        }
+        let mut best = format!("{:.0e}", value);
+        for precision in 1..=16 {
+            let text = format!("{:.*e}", precision, value);
+            if text.len() > FIXED_NUMBER_WIDTH {
+                break;
+            }
+            if text.parse::<f64>() == Ok(value) {
+                return text;
+            }
+            best = text;
+        }
+        best
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Row {
+    Objective,
+    Constraint(usize),
+    Ignored,
+}
+
+struct RowData {
+    comparison: Comparison,
+    expression: LinearExpr,
+    rhs: f64,
+    range: Option<f64>,
+}
+
+fn parse_number(token: &str) -> Result<f64, String> {
+    token
+        .parse()
+        .map_err(|_| format!("Invalid number '{}'", token))
+}
+
+impl MathProgram {
+    /// Writes the program in MPS format. Integer columns are enclosed in
+    /// `INTORG`/`INTEND` markers and binary columns get a `BV` bound.
+    pub fn to_mps(&self, format: MpsFormat) -> String {
+        let columns = column_names(self, |name| format.admits(name));
+        let mut entries: Vec<Vec<(String, f64)>> = vec![Vec::new(); columns.len()];
+        for (var, coefficient) in self.objective().terms() {
+            entries[var.index()].push((String::from(OBJECTIVE_ROW), *coefficient));
+        }
+        for (index, constraint) in self.constraints().iter().enumerate() {
+            for (var, coefficient) in constraint.expression().terms() {
+                entries[var.index()].push((row_name(index), *coefficient));
+            }
+        }
+
+        let mut out = match (format, self.name()) {
+            (_, "") => String::from("NAME\n"),
+            (MpsFormat::Fixed, name) => format!("NAME          {}\n", name),
+            (MpsFormat::Free, name) => format!("NAME {}\n", name),
+        };
+        if self.sense() == Sense::Maximize {
+            out.push_str("OBJSENSE\n");
+            out.push_str(&format.line(&["", "MAX"]));
+        }
+
+        out.push_str("ROWS\n");
+        out.push_str(&format.line(&["N", OBJECTIVE_ROW]));
+        for (index, constraint) in self.constraints().iter().enumerate() {
+            let kind = match constraint.comparison() {
+                Comparison::LessOrEqual => "L",
+                Comparison::GreaterOrEqual => "G",
+                Comparison::Equal => "E",
+            };
+            out.push_str(&format.line(&[kind, &row_name(index)]));
+        }
+
+        out.push_str("COLUMNS\n");
+        let mut integral = false;
+        for (var, name) in self.variables().zip(columns.iter()) {
+            if self.variable(var).kind().is_integral() != integral {
+                integral = !integral;
+                let marker = if integral { "'INTORG'" } else { "'INTEND'" };
+                out.push_str(&format.line(&["", "MARKER", "'MARKER'", "", marker]));
+            }
+            let column = &entries[var.index()];
+            if column.is_empty() {
+                out.push_str(&format.line(&["", name, OBJECTIVE_ROW, "0"]));
+            }
+            for (row, coefficient) in column {
+                out.push_str(&format.line(&["", name, row, &format.number(*coefficient)]));
+            }
+        }
+        if integral {
+            out.push_str(&format.line(&["", "MARKER", "'MARKER'", "", "'INTEND'"]));
+        }
+
+        out.push_str("RHS\n");
+        if self.objective().constant() != 0.0 {
+            let rhs = format.number(-self.objective().constant());
+            out.push_str(&format.line(&["", "RHS", OBJECTIVE_ROW, &rhs]));
+        }
+        for (index, constraint) in self.constraints().iter().enumerate() {
+            if constraint.rhs() != 0.0 {
+                let rhs = format.number(constraint.rhs());
+                out.push_str(&format.line(&["", "RHS", &row_name(index), &rhs]));
+            }
+        }
+
+        out.push_str("BOUNDS\n");
+        for (var, name) in self.variables().zip(columns.iter()) {
+            let info = self.variable(var);
+            let mut bound = |kind: &str, value: Option<f64>| {
+                let value = value.map(|value| format.number(value)).unwrap_or_default();
+                out.push_str(&format.line(&[kind, "BND", name, &value]));
+            };
+            match (info.kind(), info.lower(), info.upper()) {
+                (VariableKind::Binary, Some(lower), Some(upper))
+                    if lower == 0.0 && upper == 1.0 =>
+                {
+                    bound("BV", None)
+                }
+                (_, None, None) => bound("FR", None),
+                (_, Some(lower), Some(upper)) if lower == upper => bound("FX", Some(lower)),
+                (kind, lower, upper) => {
+                    match lower {
+                        None => bound("MI", None),
+                        Some(lower) if lower != 0.0 || matches!(upper, Some(u) if u < 0.0) => {
+                            bound("LO", Some(lower))
+                        }
+                        Some(_) => {}
+                    }
+                    match upper {
+                        Some(upper) => bound("UP", Some(upper)),
+                        None if kind.is_integral() => bound("PL", None),
+                        None => {}
+                    }
+                }
+            }
+        }
+        out.push_str("ENDATA\n");
+        out
+    }
+
+    /// Parses a program in fixed or free MPS format. Fields are split at whitespace,
+    /// so names containing spaces are not supported. Ranged rows are turned into
+    /// two constraints.
+    pub fn from_mps(text: &str) -> Result<MathProgram, String> {
+        let mut name = String::new();
+        let mut sense = Sense::Minimize;
+        let mut builder = ProgramBuilder::default();
+        let mut rows: HashMap<String, Row> = HashMap::new();
+        let mut objective_row: Option<String> = None;
+        let mut data: Vec<RowData> = Vec::new();
+        let mut objective = LinearExpr::new();
+        let mut section = String::new();
+        let mut integral = false;
+
+        let row = |rows: &HashMap<String, Row>, name: &str| {
+            rows.get(name)
+                .copied()
+                .ok_or_else(|| format!("Unknown row '{}'", name))
+        };
+
+        for line in text.lines() {
+            if line.trim().is_empty() || line.starts_with('*') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if !line.starts_with(char::is_whitespace) {
+                section = fields[0].to_uppercase();
+                match section.as_str() {
+                    "NAME" => name = fields[1..].join(" "),
+                    "OBJSENSE" if fields.len() > 1 => sense = parse_sense(fields[1])?,
+                    "OBJSENSE" | "ROWS" | "COLUMNS" | "RHS" | "RANGES" | "BOUNDS" => {}
+                    "ENDATA" => break,
+                    _ => return Err(format!("Unknown section '{}'", fields[0])),
+                }
+                continue;
+            }
+
+            match section.as_str() {
+                "OBJSENSE" => sense = parse_sense(fields[0])?,
+                "ROWS" => {
+                    let (kind, row_name) = match fields.as_slice() {
+                        [kind, row_name] => (kind.to_uppercase(), *row_name),
+                        _ => return Err(format!("Invalid row '{}'", line.trim())),
+                    };
+                    let comparison = match kind.as_str() {
+                        "N" => {
+                            let row = if objective_row.is_none() {
+                                objective_row = Some(String::from(row_name));
+                                Row::Objective
+                            } else {
+                                Row::Ignored
+                            };
+                            rows.insert(String::from(row_name), row);
+                            continue;
+                        }
+                        "L" => Comparison::LessOrEqual,
+                        "G" => Comparison::GreaterOrEqual,
+                        "E" => Comparison::Equal,
+                        _ => return Err(format!("Unknown row type '{}'", kind)),
+                    };
+                    rows.insert(String::from(row_name), Row::Constraint(data.len()));
+                    data.push(RowData {
+                        comparison,
+                        expression: LinearExpr::new(),
+                        rhs: 0.0,
+                        range: None,
+                    });
+                }
+                "COLUMNS" => {
+                    if fields.get(1) == Some(&"'MARKER'") {
+                        match fields.get(2) {
+                            Some(&"'INTORG'") => integral = true,
+                            Some(&"'INTEND'") => integral = false,
+                            _ => return Err(format!("Invalid marker '{}'", line.trim())),
+                        }
+                        continue;
+                    }
+                    if fields.len() != 3 && fields.len() != 5 {
+                        return Err(format!("Invalid column entry '{}'", line.trim()));
+                    }
+                    let var = builder.variable(fields[0]);
+                    if integral {
+                        builder.info_mut(var).kind = VariableKind::Integer;
+                    }
+                    for pair in fields[1..].chunks(2) {
+                        let coefficient = parse_number(pair[1])?;
+                        match row(&rows, pair[0])? {
+                            Row::Objective => objective += coefficient * var,
+                            Row::Constraint(index) => data[index].expression += coefficient * var,
+                            Row::Ignored => {}
+                        }
+                    }
+                }
+                "RHS" | "RANGES" => {
+                    let pairs = if fields.len() % 2 == 1 {
+                        &fields[1..]
+                    } else {
+                        &fields[..]
+                    };
+                    for pair in pairs.chunks(2) {
+                        let value = parse_number(pair[1])?;
+                        match (section.as_str(), row(&rows, pair[0])?) {
+                            ("RHS", Row::Objective) => objective += -value,
+                            ("RHS", Row::Constraint(index)) => data[index].rhs = value,
+                            ("RANGES", Row::Constraint(index)) => data[index].range = Some(value),
+                            _ => {}
+                        }
+                    }
+                }
+                "BOUNDS" => {
+                    let kind = fields[0].to_uppercase();
+                    let valued = !matches!(kind.as_str(), "FR" | "MI" | "PL" | "BV");
+                    let (column, value) = match (valued, fields.len()) {
+                        (true, 4) => (fields[2], Some(parse_number(fields[3])?)),
+                        (true, 3) => (fields[1], Some(parse_number(fields[2])?)),
+                        (false, 3) | (false, 4) => (fields[2], None),
+                        (false, 2) => (fields[1], None),
+                        _ => return Err(format!("Invalid bound '{}'", line.trim())),
+                    };
+                    let var = builder
+                        .get(column)
+                        .ok_or_else(|| format!("Unknown column '{}'", column))?;
+                    let info = builder.info_mut(var);
+                    match kind.as_str() {
+                        "UP" => info.upper = value,
+                        "LO" => info.lower = value,
+                        "FX" => {
+                            info.lower = value;
+                            info.upper = value;
+                        }
+                        "FR" => {
+                            info.lower = None;
+                            info.upper = None;
+                        }
+                        "MI" => info.lower = None,
+                        "PL" => info.upper = None,
+                        "BV" => {
+                            info.kind = VariableKind::Binary;
+                            info.lower = Some(0.0);
+                            info.upper = Some(1.0);
+                        }
+                        "LI" | "UI" => {
+                            info.kind = VariableKind::Integer;
+                            if kind == "LI" {
+                                info.lower = value;
+                            } else {
+                                info.upper = value;
+                            }
+                        }
+                        _ => return Err(format!("Unsupported bound type '{}'", kind)),
+                    }
+                }
+                _ => return Err(format!("Unexpected data line '{}'", line.trim())),
+            }
+        }
+
+        let mut constraints = Vec::with_capacity(data.len());
+        for row in data {
+            let (expression, rhs) = (row.expression, row.rhs);
+            let (lower, upper) = match (row.comparison, row.range) {
+                (Comparison::LessOrEqual, range) => (range.map(|r| rhs - r.abs()), Some(rhs)),
+                (Comparison::GreaterOrEqual, range) => (Some(rhs), range.map(|r| rhs + r.abs())),
+                (Comparison::Equal, Some(range)) if range > 0.0 => (Some(rhs), Some(rhs + range)),
+                (Comparison::Equal, Some(range)) if range < 0.0 => (Some(rhs + range), Some(rhs)),
+                (Comparison::Equal, _) => {
+                    constraints.push(expression.equal(rhs));
+                    continue;
+                }
+            };
+            if let Some(lower) = lower {
+                constraints.push(expression.clone().ge(lower));
+            }
+            if let Some(upper) = upper {
+                constraints.push(expression.le(upper));
+            }
+        }
+        Ok(builder.build(&name, sense, objective, constraints))
+    }
+}
+
+fn parse_sense(field: &str) -> Result<Sense, String> {
+    match field.to_uppercase().as_str() {
+        "MIN" | "MINIMIZE" => Ok(Sense::Minimize),
+        "MAX" | "MAXIMIZE" => Ok(Sense::Maximize),
+        _ => Err(format!("Unknown objective sense '{}'", field)),
+    }
+}
+
+#[cfg(test)]
+mod test_super {
+    use super::*;
+    use crate::algorithm::Algorithm;
+    use crate::program::branch_and_bound::BranchAndBound;
+    use crate::program::{LpSolution, LpSolver};
+
+    fn program() -> MathProgram {
+        let mut program = MathProgram::new("mixed", Sense::Maximize);
+        let x = program.add_binary("x");
+        let y = program.add_integer("y", Some(-2.0), None);
+        let z = program.add_continuous("z", None, Some(-1.0));
+        program.add_continuous("unused", Some(1.0), Some(1.0));
+        program.set_objective(3.0 * x + y - 0.5 * z + 1.0);
+        program.add_constraint((x + y + z).le(4.0));
+        program.add_constraint((y - 2.0 * x).ge(-1.0));
+        program
+    }
+
+    #[test]
+    fn writing_and_reading_mps_works() {
+        for &format in [MpsFormat::Free, MpsFormat::Fixed].iter() {
+            let program = program();
+            let text = program.to_mps(format);
+            let parsed = MathProgram::from_mps(&text).unwrap();
+            assert_eq!(text, parsed.to_mps(format));
+            assert_eq!("mixed", parsed.name());
+            assert_eq!(Sense::Maximize, parsed.sense());
+            assert_eq!(4, parsed.number_of_variables());
+            for var in program.variables() {
+                assert_eq!(program.variable(var), parsed.variable(var));
+            }
+            assert_eq!(program.objective(), parsed.objective());
+            assert_eq!(program.constraints(), parsed.constraints());
+        }
+    }
+
+    #[test]
+    fn fixed_mps_falls_back_to_generic_names() {
+        let mut program = MathProgram::new("long", Sense::Minimize);
+        let var = program.add_continuous("a_long_name", Some(0.0), None);
+        program.set_objective(var);
+        let parsed = MathProgram::from_mps(&program.to_mps(MpsFormat::Fixed)).unwrap();
+        assert_eq!("x0", parsed.variable(var).name());
+        let parsed = MathProgram::from_mps(&program.to_mps(MpsFormat::Free)).unwrap();
+        assert_eq!("a_long_name", parsed.variable(var).name());
+    }
+
+    #[test]
+    fn fixed_mps_keeps_numbers_in_their_columns() {
+        let mut program = MathProgram::new("numbers", Sense::Minimize);
+        let x = program.add_continuous("x", Some(-1.0 / 7.0), Some(123456789012345.0));
+        let y = program.add_integer("y", Some(1e-300), None);
+        program.set_objective(-1.0 / 3.0 * x + 2.5e-7 * y + 0.1);
+        program.add_constraint((1e20 * x - y).le(-2.0f64.sqrt()));
+        let text = program.to_mps(MpsFormat::Fixed);
+        let sections = ["COLUMNS", "RHS", "BOUNDS"];
+        let mut section = "";
+        for line in text.lines() {
+            if !line.starts_with(' ') {
+                section = line;
+            } else if sections.contains(&section) && line.len() > 24 && !line.contains("MARKER") {
+                assert_eq!(Some(' '), line.chars().nth(23), "{}", line);
+                assert!(line.len() <= 24 + FIXED_NUMBER_WIDTH, "{}", line);
+                assert!(line[24..].parse::<f64>().is_ok(), "{}", line);
+            }
+        }
+
+        let parsed = MathProgram::from_mps(&text).unwrap();
+        let close = |a: f64, b: f64| (a - b).abs() <= 1e-6 * a.abs().max(b.abs());
+        assert!(close(-1.0 / 3.0, parsed.objective().coefficient(x)));
+        assert!(close(0.1, parsed.objective().constant()));
+        assert!(close(-2.0f64.sqrt(), parsed.constraints()[0].rhs()));
+        assert!(close(-1.0 / 7.0, parsed.variable(x).lower().unwrap()));
+        assert!(close(
+            123456789012345.0,
+            parsed.variable(x).upper().unwrap()
+        ));
+        assert_eq!(Some(1e-300), parsed.variable(y).lower());
+    }
+
+    #[test]
+    fn reading_mps_works() {
+        let text = "\
+* a small integer program with a ranged row
+NAME          SMALL
+ROWS
+ N  COST
+ L  LIM1
+ G  LIM2
+ E  MYEQN
+COLUMNS
+    MARKER                 'MARKER'                 'INTORG'
+    X1        COST         1.0   LIM1         1.0
+    X1        LIM2         1.0
+    MARKER                 'MARKER'                 'INTEND'
+    X2        COST         2.0   LIM1         1.0
+    X2        MYEQN       -1.0
+    X3        COST        -1.0   MYEQN        1.0
+RHS
+    RHS       COST        -1.5
+    RHS       LIM1         4.0   LIM2         1.0
+    RHS       MYEQN        7.0
+RANGES
+    RNG       LIM1         2.5   MYEQN       -2.0
+BOUNDS
+ UP BND       X1           4.0
+ MI BND       X2
+ UP BND       X2           1.0
+ LO BND       X3          -1.0
+ENDATA
+";
+        let program = MathProgram::from_mps(text).unwrap();
+        assert_eq!("SMALL", program.name());
+        assert_eq!(Sense::Minimize, program.sense());
+        assert_eq!(3, program.number_of_variables());
+        assert_eq!(5, program.number_of_constraints());
+        assert_eq!(1.5, program.objective().constant());
+        let x1 = program.variables().next().unwrap();
+        assert_eq!(VariableKind::Integer, program.variable(x1).kind());
+
+        match LpSolver::Simplex.run(&program) {
+            LpSolution::Solved { value, .. } => assert_eq!(Some(-4.0), value),
+            _ => panic!("Parsed program was not solved"),
+        }
+        match BranchAndBound::default().run(&program) {
+            LpSolution::Solved { value, .. } => assert_eq!(Some(-4.0), value),
+            _ => panic!("Parsed program was not solved"),
+        }
+    }
+
+    #[test]
+    fn invalid_mps_is_rejected() {
+        assert!(MathProgram::from_mps("ROWS\n N obj\nCOLUMNS\n x c0 1\nENDATA").is_err());
+        assert!(MathProgram::from_mps("ROWS\n X obj\nENDATA").is_err());
+        assert!(MathProgram::from_mps("ROWS\n N obj\nBOUNDS\n UP BND x 1\nENDATA").is_err());
+    }
+}
@@ -0,0 +1,308 @@
+use crate::algorithm::Algorithm;
+use crate::problem::{OptProblemKind, SolutionKind};
+use crate::program::model::{Constraint, LinearExpr, Sense, Variable, VariableInfo, VariableKind};
+
+pub mod branch_and_bound;
+#[cfg(feature = "exact")]
+pub mod exact;
+#[cfg(feature = "lp-modeler")]
+mod external;
+pub mod format;
+pub mod model;
+mod simplex;
+
+/// Largest distance to the nearest integer at which a value still counts as integral.
+pub const INTEGRALITY_TOLERANCE: f64 = 1e-6;
+
+#[derive(Clone, Debug)]
+pub struct MathProgram {
+    name: String,
+    sense: Sense,
+    objective: LinearExpr,
+    variables: Vec<VariableInfo>,
+    constraints: Vec<Constraint>,
+}
+
+impl MathProgram {
+    pub fn new(name: &str, sense: Sense) -> Self {
+        MathProgram {
+            name: String::from(name),
+            sense,
+            objective: LinearExpr::new(),
+            variables: Vec::new(),
+            constraints: Vec::new(),
+        }
+    }
+
+    pub fn add_variable(
+        &mut self,
+        name: &str,
+        kind: VariableKind,
+        lower: Option<f64>,
+        upper: Option<f64>,
+    ) -> Variable {
+        self.variables.push(VariableInfo {
+            name: String::from(name),
+            kind,
+            lower,
+            upper,
+        });
+        Variable(self.variables.len() - 1)
+    }
+
+    pub fn add_binary(&mut self, name: &str) -> Variable {
+        self.add_variable(name, VariableKind::Binary, Some(0.0), Some(1.0))
+    }
+
+    pub fn add_integer(&mut self, name: &str, lower: Option<f64>, upper: Option<f64>) -> Variable {
+        self.add_variable(name, VariableKind::Integer, lower, upper)
+    }
+
+    pub fn add_continuous(
+        &mut self,
+        name: &str,
+        lower: Option<f64>,
+        upper: Option<f64>,
+    ) -> Variable {
+        self.add_variable(name, VariableKind::Continuous, lower, upper)
+    }
+
+    pub fn add_constraint(&mut self, constraint: Constraint) {
+        self.constraints.push(constraint);
+    }
+
+    pub fn set_objective<T: Into<LinearExpr>>(&mut self, objective: T) {
+        self.objective = objective.into().simplified();
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn sense(&self) -> Sense {
+        self.sense
+    }
+
+    pub fn objective(&self) -> &LinearExpr {
+        &self.objective
+    }
+
+    pub fn variables(&self) -> impl Iterator<Item = Variable> {
+        (0..self.variables.len()).map(Variable)
+    }
+
+    pub fn variable(&self, variable: Variable) -> &VariableInfo {
+        &self.variables[variable.index()]
+    }
+
+    pub fn constraints(&self) -> &[Constraint] {
+        &self.constraints
+    }
+
+    pub fn number_of_variables(&self) -> usize {
+        self.variables.len()
+    }
+
+    pub fn number_of_constraints(&self) -> usize {
+        self.constraints.len()
+    }
+}
+
+impl OptProblemKind for MathProgram {
+    type Solution = LpSolution;
+    type Cost = f64;
+}
+
+pub enum LpSolver {
+    /// CBC through lp-modeler, which stores numbers as `f32`. Programs with numbers
+    /// that would be rounded, e.g. integers above `2^24`, fail instead.
+    #[cfg(feature = "lp-modeler")]
+    CBC,
+    /// GLPK through lp-modeler, with the same restriction as [`LpSolver::CBC`].
+    #[cfg(feature = "lp-modeler")]
+    GLPK,
+    /// Built-in bounded simplex method. Integrality of variables is ignored, i.e.
+    /// binary and integer variables are relaxed to their bounds.
+    Simplex,
+}
+
+pub enum SolutionType {
+    Optimal,
+    /// A feasible but not proven optimal solution. `gap` is the relative distance
+    /// between its objective value and the best known bound, if the backend reports it.
+    SubOptimal {
+        gap: Option<f64>,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BasisStatus {
+    Basic,
+    AtLower,
+    AtUpper,
+    /// Nonbasic without bounds, at value zero.
+    Free,
+}
+
+/// Dual information of an optimal basic solution of an LP. All values are given in
+/// the sense of the program: the dual of a constraint is the rate at which the
+/// optimal objective value changes with its right-hand side, and the reduced cost
+/// of a variable is its objective coefficient minus its column priced by the duals.
+///
+/// Ranges are intervals of a single objective coefficient or right-hand side over
+/// which the current basis stays optimal, where `None` stands for infinity.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DualSolution<T = f64> {
+    duals: Vec<T>,
+    reduced_costs: Vec<T>,
+    variable_status: Vec<BasisStatus>,
+    constraint_status: Vec<BasisStatus>,
+    objective_ranges: Vec<(Option<T>, Option<T>)>,
+    rhs_ranges: Vec<(Option<T>, Option<T>)>,
+}
+
+impl<T: Clone> DualSolution<T> {
+    /// Dual values indexed like [`MathProgram::constraints`].
+    pub fn duals(&self) -> &[T] {
+        &self.duals
+    }
+
+    /// Reduced costs indexed by [`Variable::index`].
+    pub fn reduced_costs(&self) -> &[T] {
+        &self.reduced_costs
+    }
+
+    pub fn dual(&self, constraint: usize) -> T {
+        self.duals[constraint].clone()
+    }
+
+    pub fn reduced_cost(&self, variable: Variable) -> T {
+        self.reduced_costs[variable.index()].clone()
+    }
+
+    pub fn variable_status(&self, variable: Variable) -> BasisStatus {
+        self.variable_status[variable.index()]
+    }
+
+    /// Status of the activity of a constraint, which is at its lower (upper) bound if
+    /// a `>=` (`<=`) constraint is binding and nonbasic.
+    pub fn constraint_status(&self, constraint: usize) -> BasisStatus {
+        self.constraint_status[constraint]
+    }
+
+    /// Range of the objective coefficient of `variable` for which the solution stays
+    /// optimal. For a nonbasic variable it is bounded by its reduced cost.
+    pub fn objective_range(&self, variable: Variable) -> (Option<T>, Option<T>) {
+        self.objective_ranges[variable.index()].clone()
+    }
+
+    /// Range of the right-hand side of a constraint for which the basis stays
+    /// feasible, so that the duals remain valid.
+    pub fn rhs_range(&self, constraint: usize) -> (Option<T>, Option<T>) {
+        self.rhs_ranges[constraint].clone()
+    }
+
+    #[cfg(feature = "exact")]
+    fn map<U, F: Fn(&T) -> U>(&self, f: F) -> DualSolution<U> {
+        let map_range = |(lower, upper): &(Option<T>, Option<T>)| {
+            (lower.as_ref().map(&f), upper.as_ref().map(&f))
+        };
+        DualSolution {
+            duals: self.duals.iter().map(&f).collect(),
+            reduced_costs: self.reduced_costs.iter().map(&f).collect(),
+            variable_status: self.variable_status.clone(),
+            constraint_status: self.constraint_status.clone(),
+            objective_ranges: self.objective_ranges.iter().map(map_range).collect(),
+            rhs_ranges: self.rhs_ranges.iter().map(map_range).collect(),
+        }
+    }
+}
+
+pub enum LpSolution {
+    Solved {
+        /// Values of all variables, indexed by [`Variable::index`].
+        vars: Vec<f64>,
+        kind: SolutionType,
+        value: Option<f64>,
+        /// Right-hand side minus activity of each constraint.
+        slacks: Vec<f64>,
+        /// `None` if the backend does not provide dual information, which is the case
+        /// for external solvers and for branch and bound.
+        duals: Option<DualSolution>,
+    },
+    Infeasible,
+    Unbounded,
+    Failed(String),
+}
+
+impl LpSolution {
+    pub fn value(&self, variable: Variable) -> Option<f64> {
+        match self {
+            LpSolution::Solved { vars, .. } => vars.get(variable.index()).copied(),
+            _ => None,
+        }
+    }
+
+    pub fn slack(&self, constraint: usize) -> Option<f64> {
+        match self {
+            LpSolution::Solved { slacks, .. } => slacks.get(constraint).copied(),
+            _ => None,
+        }
+    }
+
+    pub fn duals(&self) -> Option<&DualSolution> {
+        match self {
+            LpSolution::Solved { duals, .. } => duals.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Value of `variable` rounded to the nearest integer, or `None` if it is
+    /// further than [`INTEGRALITY_TOLERANCE`] away from it.
+    pub fn integral_value(&self, variable: Variable) -> Option<f64> {
+        self.value(variable)
+            .map(|value| (value, value.round()))
+            .filter(|(value, rounded)| (value - rounded).abs() <= INTEGRALITY_TOLERANCE)
+            .map(|(_, rounded)| rounded)
+    }
+}
+
+impl SolutionKind<MathProgram> for LpSolution {
+    fn cost(&self, _: &MathProgram) -> Option<f64> {
+        match self {
+            LpSolution::Solved { value, .. } => *value,
+            _ => None,
+        }
+    }
+}
+
+impl Algorithm<MathProgram> for LpSolver {
+    fn run(&self, instance: &MathProgram) -> LpSolution {
+        match self {
+            LpSolver::Simplex => simplex::solve(instance),
+            #[cfg(feature = "lp-modeler")]
+            LpSolver::CBC => external::solve(instance, &lp_modeler::solvers::CbcSolver::new()),
+            #[cfg(feature = "lp-modeler")]
+            LpSolver::GLPK => external::solve(instance, &lp_modeler::solvers::GlpkSolver::new()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn building_program_works() {
+        let mut program = MathProgram::new("program", Sense::Maximize);
+        let x = program.add_binary("x");
+        let y = program.add_integer("y", Some(0.0), Some(5.0));
+        program.set_objective(2.0 * x + y);
+        program.add_constraint((x + y).le(3.0));
+        assert_eq!(2, program.number_of_variables());
+        assert_eq!(1, program.number_of_constraints());
+        assert_eq!("y", program.variable(y).name());
+        assert_eq!(VariableKind::Binary, program.variable(x).kind());
+        assert_eq!(2.0, program.objective().coefficient(x));
+    }
+}
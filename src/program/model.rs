@@ -0,0 +1,298 @@
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+/// Handle of a variable of a [`MathProgram`](super::MathProgram). Handles are
+/// only meaningful for the program that created them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Variable(pub(crate) usize);
+
+impl Variable {
+    /// Handle of the variable with the given index, i.e. of the `index + 1`-th variable
+    /// added to a program.
+    pub fn new(index: usize) -> Self {
+        Variable(index)
+    }
+
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VariableKind {
+    Continuous,
+    Integer,
+    Binary,
+}
+
+impl VariableKind {
+    pub fn is_integral(&self) -> bool {
+        !matches!(self, VariableKind::Continuous)
+    }
+}
+
+/// Name, kind and bounds of a variable. A missing bound stands for infinity.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VariableInfo {
+    pub(crate) name: String,
+    pub(crate) kind: VariableKind,
+    pub(crate) lower: Option<f64>,
+    pub(crate) upper: Option<f64>,
+}
+
+impl VariableInfo {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn kind(&self) -> VariableKind {
+        self.kind
+    }
+
+    pub fn lower(&self) -> Option<f64> {
+        self.lower
+    }
+
+    pub fn upper(&self) -> Option<f64> {
+        self.upper
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Sense {
+    Minimize,
+    Maximize,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LinearExpr {
+    terms: Vec<(Variable, f64)>,
+    constant: f64,
+}
+
+impl LinearExpr {
+    pub fn new() -> Self {
+        LinearExpr::default()
+    }
+
+    pub fn terms(&self) -> &[(Variable, f64)] {
+        &self.terms
+    }
+
+    pub fn constant(&self) -> f64 {
+        self.constant
+    }
+
+    pub fn coefficient(&self, variable: Variable) -> f64 {
+        self.terms
+            .iter()
+            .filter(|(var, _)| *var == variable)
+            .map(|(_, coefficient)| coefficient)
+            .sum()
+    }
+
+    /// Merges repeated variables and drops vanishing terms.
+    pub fn simplified(&self) -> LinearExpr {
+        let mut terms: Vec<(Variable, f64)> = Vec::with_capacity(self.terms.len());
+        let mut sorted = self.terms.clone();
+        sorted.sort_by_key(|(var, _)| *var);
+        for (var, coefficient) in sorted {
+            match terms.last_mut() {
+                Some((last, sum)) if *last == var => *sum += coefficient,
+                _ => terms.push((var, coefficient)),
+            }
+        }
+        terms.retain(|(_, coefficient)| *coefficient != 0.0);
+        LinearExpr {
+            terms,
+            constant: self.constant,
+        }
+    }
+
+    pub fn evaluate(&self, values: &[f64]) -> f64 {
+        self.constant
+            + self
+                .terms
+                .iter()
+                .map(|(var, coefficient)| coefficient * values[var.index()])
+                .sum::<f64>()
+    }
+
+    pub fn le<T: Into<LinearExpr>>(self, rhs: T) -> Constraint {
+        Constraint::new(self - rhs, Comparison::LessOrEqual)
+    }
+
+    pub fn ge<T: Into<LinearExpr>>(self, rhs: T) -> Constraint {
+        Constraint::new(self - rhs, Comparison::GreaterOrEqual)
+    }
+
+    pub fn equal<T: Into<LinearExpr>>(self, rhs: T) -> Constraint {
+        Constraint::new(self - rhs, Comparison::Equal)
+    }
+}
+
+impl From<Variable> for LinearExpr {
+    fn from(variable: Variable) -> Self {
+        LinearExpr {
+            terms: vec![(variable, 1.0)],
+            constant: 0.0,
+        }
+    }
+}
+
+impl From<f64> for LinearExpr {
+    fn from(constant: f64) -> Self {
+        LinearExpr {
+            terms: Vec::new(),
+            constant,
+        }
+    }
+}
+
+impl<T: Into<LinearExpr>> Add<T> for LinearExpr {
+    type Output = LinearExpr;
+
+    fn add(mut self, rhs: T) -> LinearExpr {
+        self += rhs;
+        self
+    }
+}
+
+impl<T: Into<LinearExpr>> AddAssign<T> for LinearExpr {
+    fn add_assign(&mut self, rhs: T) {
+        let rhs = rhs.into();
+        self.terms.extend(rhs.terms);
+        self.constant += rhs.constant;
+    }
+}
+
+impl<T: Into<LinearExpr>> Sub<T> for LinearExpr {
+    type Output = LinearExpr;
+
+    fn sub(self, rhs: T) -> LinearExpr {
+        self + -rhs.into()
+    }
+}
+
+impl Neg for LinearExpr {
+    type Output = LinearExpr;
+
+    fn neg(self) -> LinearExpr {
+        -1.0 * self
+    }
+}
+
+impl Mul<LinearExpr> for f64 {
+    type Output = LinearExpr;
+
+    fn mul(self, rhs: LinearExpr) -> LinearExpr {
+        LinearExpr {
+            terms: rhs
+                .terms
+                .into_iter()
+                .map(|(var, coefficient)| (var, self * coefficient))
+                .collect(),
+            constant: self * rhs.constant,
+        }
+    }
+}
+
+impl Mul<Variable> for f64 {
+    type Output = LinearExpr;
+
+    fn mul(self, rhs: Variable) -> LinearExpr {
+        LinearExpr {
+            terms: vec![(rhs, self)],
+            constant: 0.0,
+        }
+    }
+}
+
+impl<T: Into<LinearExpr>> Add<T> for Variable {
+    type Output = LinearExpr;
+
+    fn add(self, rhs: T) -> LinearExpr {
+        LinearExpr::from(self) + rhs
+    }
+}
+
+impl<T: Into<LinearExpr>> Sub<T> for Variable {
+    type Output = LinearExpr;
+
+    fn sub(self, rhs: T) -> LinearExpr {
+        LinearExpr::from(self) - rhs
+    }
+}
+
+impl Sum for LinearExpr {
+    fn sum<It: Iterator<Item = LinearExpr>>(iter: It) -> LinearExpr {
+        iter.fold(LinearExpr::new(), |sum, expr| sum + expr)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Comparison {
+    LessOrEqual,
+    GreaterOrEqual,
+    Equal,
+}
+
+/// Linear constraint `expression <comparison> rhs`, where the expression carries
+/// no constant term.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Constraint {
+    expression: LinearExpr,
+    comparison: Comparison,
+    rhs: f64,
+}
+
+impl Constraint {
+    pub fn new(expression: LinearExpr, comparison: Comparison) -> Self {
+        let mut expression = expression.simplified();
+        let rhs = -expression.constant;
+        expression.constant = 0.0;
+        Constraint {
+            expression,
+            comparison,
+            rhs,
+        }
+    }
+
+    pub fn expression(&self) -> &LinearExpr {
+        &self.expression
+    }
+
+    pub fn comparison(&self) -> Comparison {
+        self.comparison
+    }
+
+    pub fn rhs(&self) -> f64 {
+        self.rhs
+    }
+
+    pub fn is_satisfied(&self, values: &[f64], tolerance: f64) -> bool {
+        let activity = self.expression.evaluate(values);
+        match self.comparison {
+            Comparison::LessOrEqual => activity <= self.rhs + tolerance,
+            Comparison::GreaterOrEqual => activity >= self.rhs - tolerance,
+            Comparison::Equal => (activity - self.rhs).abs() <= tolerance,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn building_constraints_works() {
+        let (x, y) = (Variable(0), Variable(1));
+        let constraint = (2.0 * x + y + 3.0 - x).le(4.0 * y + 5.0);
+        assert_eq!(1.0, constraint.expression().coefficient(x));
+        assert_eq!(-3.0, constraint.expression().coefficient(y));
+        assert_eq!(2, constraint.expression().terms().len());
+        assert_eq!(2.0, constraint.rhs());
+        assert!(constraint.is_satisfied(&[1.0, 0.0], 0.0));
+        assert!(!constraint.is_satisfied(&[3.0, 0.0], 0.0));
+    }
+}
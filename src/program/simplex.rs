@@ -0,0 +1,889 @@
+use crate::primitives::Zero;
+use crate::program::model::{Comparison, Sense, VariableInfo};
+use crate::program::{BasisStatus, DualSolution, LpSolution, MathProgram, SolutionType};
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+const DEGENERATE_PIVOTS_BEFORE_BLAND: usize = 50;
+const ITERATIONS_PER_DIMENSION: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Status {
+    Optimal,
+    Infeasible,
+    Unbounded,
+    IterationLimit,
+}
+
+/// A linear program `min c^T x + offset` subject to `lower <= Ax <= upper` and
+/// variable bounds, where a missing bound stands for infinity.
+#[derive(Clone, Debug)]
+pub(crate) struct LinearProgram {
+    maximize: bool,
+    cost: Vec<f64>,
+    offset: f64,
+    lower: Vec<Option<f64>>,
+    upper: Vec<Option<f64>>,
+    integer: Vec<bool>,
+    rows: Vec<Row>,
+}
+
+#[derive(Clone, Debug)]
+struct Row {
+    coefficients: Vec<(usize, f64)>,
+    lower: Option<f64>,
+    upper: Option<f64>,
+}
+
+impl LinearProgram {
+    pub(crate) fn new(program: &MathProgram) -> Self {
+        let maximize = program.sense() == Sense::Maximize;
+        let sign = if maximize { -1.0 } else { 1.0 };
+
+        let mut cost = vec![0.0; program.number_of_variables()];
+        for (var, coefficient) in program.objective().terms() {
+            cost[var.index()] += sign * coefficient;
+        }
+
+        let rows = program
+            .constraints()
+            .iter()
+            .map(|constraint| {
+                let rhs = Some(constraint.rhs());
+                let (lower, upper) = match constraint.comparison() {
+                    Comparison::LessOrEqual => (None, rhs),
+                    Comparison::GreaterOrEqual => (rhs, None),
+                    Comparison::Equal => (rhs, rhs),
+                };
+                Row {
+                    coefficients: constraint
+                        .expression()
+                        .terms()
+                        .iter()
+                        .map(|(var, coefficient)| (var.index(), *coefficient))
+                        .collect(),
+                    lower,
+                    upper,
+                }
+            })
+            .collect();
+
+        let variables: Vec<&VariableInfo> = program
+            .variables()
+            .map(|var| program.variable(var))
+            .collect();
+        LinearProgram {
+            maximize,
+            cost,
+            offset: sign * program.objective().constant(),
+            lower: variables.iter().map(|info| info.lower()).collect(),
+            upper: variables.iter().map(|info| info.upper()).collect(),
+            integer: variables
+                .iter()
+                .map(|info| info.kind().is_integral())
+                .collect(),
+            rows,
+        }
+    }
+
+    pub(crate) fn number_of_variables(&self) -> usize {
+        self.cost.len()
+    }
+
+    pub(crate) fn is_integer(&self, column: usize) -> bool {
+        self.integer[column]
+    }
+
+    pub(crate) fn bounds(&self, column: usize) -> (Option<f64>, Option<f64>) {
+        (self.lower[column], self.upper[column])
+    }
+
+    pub(crate) fn objective_value<T: Scalar>(&self, values: &[T]) -> T {
+        let value = self
+            .cost
+            .iter()
+            .zip(values.iter())
+            .fold(T::from_f64(self.offset), |sum, (cost, value)| {
+                sum + T::from_f64(*cost) * value.clone()
+            });
+        if self.maximize {
+            -value
+        } else {
+            value
+        }
+    }
+
+    /// Right-hand side minus activity of each row.
+    pub(crate) fn slacks<T: Scalar>(&self, values: &[T]) -> Vec<T> {
+        self.rows
+            .iter()
+            .map(|row| {
+                let rhs = T::from_f64(row.upper.or(row.lower).unwrap_or(0.0));
+                row.coefficients
+                    .iter()
+                    .fold(rhs, |slack, &(j, coefficient)| {
+                        slack - T::from_f64(coefficient) * values[j].clone()
+                    })
+            })
+            .collect()
+    }
+
+    /// Duals and reduced costs of an optimal tableau in the sense of the program.
+    pub(crate) fn dual_solution<T: Scalar>(&self, tableau: &Tableau<T>) -> DualSolution<T> {
+        let n = self.number_of_variables();
+        let m = self.rows.len();
+        let reduced = tableau.reduced_costs();
+        let in_sense = |column: usize| match (tableau.status(column), self.maximize) {
+            (BasisStatus::Basic, _) => T::zero(),
+            (_, true) => -reduced[column].clone(),
+            (_, false) => reduced[column].clone(),
+        };
+        let objective_ranges = (0..n)
+            .map(|column| {
+                let cost = T::from_f64(self.cost[column]);
+                let (lower, upper) = tableau.cost_range(column, &reduced);
+                let lower = lower.map(|delta| cost.clone() + delta);
+                let upper = upper.map(|delta| cost.clone() + delta);
+                if self.maximize {
+                    (upper.map(|cost| -cost), lower.map(|cost| -cost))
+                } else {
+                    (lower, upper)
+                }
+            })
+            .collect();
+        let rhs_ranges = self
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let column = n + i;
+                if tableau.status(column) == BasisStatus::Basic {
+                    // The constraint stays slack as long as it admits the current activity.
+                    let activity = &tableau.values()[column];
+                    (
+                        row.upper.map(|_| activity.clone()),
+                        row.lower.map(|_| activity.clone()),
+                    )
+                } else {
+                    let rhs = T::from_f64(row.upper.or(row.lower).unwrap_or(0.0));
+                    let (lower, upper) = tableau.value_range(column);
+                    (
+                        lower.map(|delta| rhs.clone() + delta),
+                        upper.map(|delta| rhs.clone() + delta),
+                    )
+                }
+            })
+            .collect();
+        DualSolution {
+            duals: (n..n + m).map(in_sense).collect(),
+            reduced_costs: (0..n).map(in_sense).collect(),
+            variable_status: (0..n).map(|column| tableau.status(column)).collect(),
+            constraint_status: (n..n + m).map(|column| tableau.status(column)).collect(),
+            objective_ranges,
+            rhs_ranges,
+        }
+    }
+
+    pub(crate) fn solution(
+        &self,
+        values: &[f64],
+        kind: SolutionType,
+        duals: Option<DualSolution>,
+    ) -> LpSolution {
+        LpSolution::Solved {
+            vars: values.to_vec(),
+            kind,
+            value: Some(self.objective_value(values)),
+            slacks: self.slacks(values),
+            duals,
+        }
+    }
+}
+
+/// Number type the simplex method computes with. Floating point scalars compare
+/// up to a tolerance, exact scalars compare exactly.
+pub(crate) trait Scalar:
+    Clone
+    + Debug
+    + PartialOrd
+    + Zero
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    fn from_f64(value: f64) -> Self;
+    /// Magnitude up to which entries, reduced costs and infeasibilities count as zero.
+    fn tolerance() -> Self;
+    /// Largest sum of artificial variables that is still accepted as feasible.
+    fn feasibility_tolerance() -> Self;
+
+    fn abs(&self) -> Self {
+        if *self < Self::zero() {
+            -self.clone()
+        } else {
+            self.clone()
+        }
+    }
+}
+
+impl Scalar for f64 {
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn tolerance() -> Self {
+        1e-9
+    }
+
+    fn feasibility_tolerance() -> Self {
+        1e-6
+    }
+}
+
+/// Dense simplex tableau over the structural variables followed by one logical
+/// variable per row (its activity) and possibly artificial variables.
+#[derive(Clone, Debug)]
+pub(crate) struct Tableau<T: Scalar> {
+    matrix: Vec<Vec<T>>,
+    basis: Vec<usize>,
+    values: Vec<T>,
+    lower: Vec<Option<T>>,
+    upper: Vec<Option<T>>,
+    cost: Vec<T>,
+    iteration_limit: usize,
+}
+
+impl<T: Scalar> Tableau<T> {
+    pub(crate) fn new(program: &LinearProgram) -> Self {
+        let n = program.number_of_variables();
+        let m = program.rows.len();
+        let convert = |bound: &Option<f64>| bound.map(T::from_f64);
+
+        let mut lower: Vec<Option<T>> = program.lower.iter().map(convert).collect();
+        let mut upper: Vec<Option<T>> = program.upper.iter().map(convert).collect();
+        let mut values: Vec<T> = lower
+            .iter()
+            .zip(upper.iter())
+            .map(|bounds| match bounds {
+                (Some(lower), _) => lower.clone(),
+                (None, Some(upper)) => upper.clone(),
+                (None, None) => T::zero(),
+            })
+            .collect();
+
+        let mut matrix = vec![vec![T::zero(); n + m]; m];
+        for (i, row) in program.rows.iter().enumerate() {
+            let mut activity = T::zero();
+            for &(j, coefficient) in &row.coefficients {
+                let coefficient = T::from_f64(coefficient);
+                activity = activity + coefficient.clone() * values[j].clone();
+                matrix[i][j] = -coefficient;
+            }
+            matrix[i][n + i] = T::from_f64(1.0);
+            lower.push(convert(&row.lower));
+            upper.push(convert(&row.upper));
+            values.push(activity);
+        }
+
+        let mut cost: Vec<T> = program.cost.iter().map(|&cost| T::from_f64(cost)).collect();
+        cost.resize(n + m, T::zero());
+
+        Tableau {
+            matrix,
+            basis: (n..n + m).collect(),
+            values,
+            lower,
+            upper,
+            cost,
+            iteration_limit: ITERATIONS_PER_DIMENSION * (n + m + 1),
+        }
+    }
+
+    pub(crate) fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    pub(crate) fn bounds(&self, column: usize) -> (Option<T>, Option<T>) {
+        (self.lower[column].clone(), self.upper[column].clone())
+    }
+
+    /// Objective value of the current basic solution, without the constant offset
+    /// and in the minimization sense.
+    pub(crate) fn objective(&self) -> T {
+        self.cost
+            .iter()
+            .zip(self.values.iter())
+            .fold(T::zero(), |sum, (cost, value)| {
+                sum + cost.clone() * value.clone()
+            })
+    }
+
+    /// Replaces the bounds of a variable. A nonbasic variable is moved onto its new
+    /// bound, so that the tableau can afterwards be repaired by [`Tableau::reoptimize`].
+    pub(crate) fn set_bounds(&mut self, column: usize, lower: Option<T>, upper: Option<T>) {
+        self.lower[column] = lower.clone();
+        self.upper[column] = upper.clone();
+        if !self.is_basic(column) {
+            let value = self.values[column].clone();
+            let target = match (lower, upper) {
+                (Some(lower), _) if value < lower => lower,
+                (_, Some(upper)) if value > upper => upper,
+                _ => value.clone(),
+            };
+            self.shift(column, target.clone() - value);
+            self.values[column] = target;
+        }
+    }
+
+    /// Restores optimality of a previously optimal tableau after bound changes by
+    /// the dual simplex method.
+    pub(crate) fn reoptimize(&mut self) -> Status {
+        if self.is_primal_feasible() {
+            Status::Optimal
+        } else {
+            self.dual()
+        }
+    }
+
+    pub(crate) fn solve(&mut self) -> Status {
+        if self.is_primal_feasible() {
+            return self.primal();
+        }
+        if self.is_dual_feasible(&self.reduced_costs()) {
+            return self.dual();
+        }
+        match self.phase_one() {
+            Status::Optimal => self.primal(),
+            status => status,
+        }
+    }
+
+    pub(crate) fn status(&self, column: usize) -> BasisStatus {
+        if self.is_basic(column) {
+            return BasisStatus::Basic;
+        }
+        let value = &self.values[column];
+        match (&self.lower[column], &self.upper[column]) {
+            (Some(lower), _) if (value.clone() - lower.clone()).abs() <= T::tolerance() => {
+                BasisStatus::AtLower
+            }
+            (_, Some(upper)) if (value.clone() - upper.clone()).abs() <= T::tolerance() => {
+                BasisStatus::AtUpper
+            }
+            _ => BasisStatus::Free,
+        }
+    }
+
+    /// Interval of changes of the cost of `column` for which the basis stays dual
+    /// feasible, given the current reduced costs.
+    pub(crate) fn cost_range(&self, column: usize, reduced: &[T]) -> (Option<T>, Option<T>) {
+        let mut range = (None, None);
+        match self.basis.iter().position(|&basic| basic == column) {
+            None => {
+                let delta = -reduced[column].clone();
+                if self.can_increase(column) {
+                    range.0 = Some(delta.clone());
+                }
+                if self.can_decrease(column) {
+                    range.1 = Some(delta);
+                }
+            }
+            Some(row) => {
+                // The reduced cost of a nonbasic column k changes by `-delta * entry`.
+                for (k, entry) in self.matrix[row].iter().enumerate() {
+                    if self.is_basic(k) || entry.abs() <= T::tolerance() {
+                        continue;
+                    }
+                    let ratio = reduced[k].clone() / entry.clone();
+                    let positive = *entry > T::zero();
+                    if self.can_increase(k) {
+                        tighten(&mut range, ratio.clone(), positive);
+                    }
+                    if self.can_decrease(k) {
+                        tighten(&mut range, ratio, !positive);
+                    }
+                }
+            }
+        }
+        clamp(range)
+    }
+
+    /// Interval of changes of the value of the nonbasic `column` for which all basic
+    /// variables stay within their bounds.
+    pub(crate) fn value_range(&self, column: usize) -> (Option<T>, Option<T>) {
+        let mut range = (None, None);
+        for (row, &basic) in self.matrix.iter().zip(self.basis.iter()) {
+            let rate = -row[column].clone();
+            if rate.abs() <= T::tolerance() {
+                continue;
+            }
+            let positive = rate > T::zero();
+            let value = &self.values[basic];
+            if let Some(upper) = &self.upper[basic] {
+                tighten(
+                    &mut range,
+                    (upper.clone() - value.clone()) / rate.clone(),
+                    positive,
+                );
+            }
+            if let Some(lower) = &self.lower[basic] {
+                tighten(
+                    &mut range,
+                    (lower.clone() - value.clone()) / rate.clone(),
+                    !positive,
+                );
+            }
+        }
+        clamp(range)
+    }
+
+    fn columns(&self) -> usize {
+        self.values.len()
+    }
+
+    fn is_basic(&self, column: usize) -> bool {
+        self.basis.contains(&column)
+    }
+
+    fn can_increase(&self, column: usize) -> bool {
+        match &self.upper[column] {
+            Some(upper) => self.values[column] < upper.clone() - T::tolerance(),
+            None => true,
+        }
+    }
+
+    fn can_decrease(&self, column: usize) -> bool {
+        match &self.lower[column] {
+            Some(lower) => self.values[column] > lower.clone() + T::tolerance(),
+            None => true,
+        }
+    }
+
+    fn infeasibility(&self, column: usize) -> T {
+        let value = self.values[column].clone();
+        match (&self.lower[column], &self.upper[column]) {
+            (Some(lower), _) if value < lower.clone() - T::tolerance() => value - lower.clone(),
+            (_, Some(upper)) if value > upper.clone() + T::tolerance() => value - upper.clone(),
+            _ => T::zero(),
+        }
+    }
+
+    fn is_primal_feasible(&self) -> bool {
+        self.basis
+            .iter()
+            .all(|&column| self.infeasibility(column) == T::zero())
+    }
+
+    pub(crate) fn reduced_costs(&self) -> Vec<T> {
+        let mut reduced = self.cost.clone();
+        for (row, &basic) in self.matrix.iter().zip(self.basis.iter()) {
+            let cost = &self.cost[basic];
+            if *cost != T::zero() {
+                for (reduced, entry) in reduced.iter_mut().zip(row.iter()) {
+                    *reduced = reduced.clone() - cost.clone() * entry.clone();
+                }
+            }
+        }
+        reduced
+    }
+
+    fn is_dual_feasible(&self, reduced: &[T]) -> bool {
+        (0..self.columns())
+            .filter(|&column| !self.is_basic(column))
+            .all(|column| {
+                (reduced[column] >= -T::tolerance() || !self.can_increase(column))
+                    && (reduced[column] <= T::tolerance() || !self.can_decrease(column))
+            })
+    }
+
+    fn pivot(&mut self, row: usize, column: usize) {
+        let pivot = self.matrix[row][column].clone();
+        for entry in self.matrix[row].iter_mut() {
+            *entry = entry.clone() / pivot.clone();
+        }
+        let negligible = T::tolerance() * T::tolerance();
+        let pivot_row = self.matrix[row].clone();
+        for (i, other) in self.matrix.iter_mut().enumerate() {
+            let factor = other[column].clone();
+            if i != row && factor != T::zero() {
+                for (entry, pivot_entry) in other.iter_mut().zip(pivot_row.iter()) {
+                    *entry = entry.clone() - factor.clone() * pivot_entry.clone();
+                    if entry.abs() < negligible {
+                        *entry = T::zero();
+                    }
+                }
+                other[column] = T::zero();
+            }
+        }
+        self.basis[row] = column;
+    }
+
+    /// Moves the nonbasic `column` by `step` and lets the basic variables follow.
+    fn shift(&mut self, column: usize, step: T) {
+        self.values[column] = self.values[column].clone() + step.clone();
+        for (row, &basic) in self.matrix.iter().zip(self.basis.iter()) {
+            self.values[basic] = self.values[basic].clone() - row[column].clone() * step.clone();
+        }
+    }
+
+    fn primal(&mut self) -> Status {
+        let mut degenerate_pivots = 0;
+        for _ in 0..self.iteration_limit {
+            let reduced = self.reduced_costs();
+            let bland = degenerate_pivots > DEGENERATE_PIVOTS_BEFORE_BLAND;
+
+            let mut entering: Option<(usize, bool)> = None;
+            for column in 0..self.columns() {
+                if self.is_basic(column) {
+                    continue;
+                }
+                let increase = if reduced[column] < -T::tolerance() && self.can_increase(column) {
+                    true
+                } else if reduced[column] > T::tolerance() && self.can_decrease(column) {
+                    false
+                } else {
+                    continue;
+                };
+                match entering {
+                    Some((best, _)) if bland || reduced[best].abs() >= reduced[column].abs() => {}
+                    _ => entering = Some((column, increase)),
+                }
+            }
+            let (column, increase) = match entering {
+                Some(entering) => entering,
+                None => return Status::Optimal,
+            };
+
+            let value = &self.values[column];
+            let mut step = if increase {
+                self.upper[column]
+                    .clone()
+                    .map(|upper| upper - value.clone())
+            } else {
+                self.lower[column]
+                    .clone()
+                    .map(|lower| value.clone() - lower)
+            };
+            let mut leaving: Option<(usize, T)> = None;
+            for (row, &basic) in self.basis.iter().enumerate() {
+                let rate = if increase {
+                    -self.matrix[row][column].clone()
+                } else {
+                    self.matrix[row][column].clone()
+                };
+                let (limit, bound) = if rate < -T::tolerance() {
+                    match &self.lower[basic] {
+                        Some(lower) => (
+                            (self.values[basic].clone() - lower.clone()) / -rate.clone(),
+                            lower.clone(),
+                        ),
+                        None => continue,
+                    }
+                } else if rate > T::tolerance() {
+                    match &self.upper[basic] {
+                        Some(upper) => (
+                            (upper.clone() - self.values[basic].clone()) / rate.clone(),
+                            upper.clone(),
+                        ),
+                        None => continue,
+                    }
+                } else {
+                    continue;
+                };
+                let limit = if limit < T::zero() { T::zero() } else { limit };
+                let better = match &step {
+                    None => true,
+                    Some(step) => {
+                        limit < step.clone() - T::tolerance()
+                            || (limit <= step.clone() + T::tolerance()
+                                && !bland
+                                && matches!(&leaving, Some((best, _))
+                                    if rate.abs() > self.matrix[*best][column].abs()))
+                    }
+                };
+                if better {
+                    step = Some(limit);
+                    leaving = Some((row, bound));
+                }
+            }
+
+            let step = match step {
+                Some(step) => step,
+                None => return Status::Unbounded,
+            };
+            if step <= T::tolerance() {
+                degenerate_pivots += 1;
+            } else {
+                degenerate_pivots = 0;
+            }
+
+            self.shift(column, if increase { step } else { -step });
+            match leaving {
+                Some((row, bound)) => {
+                    let basic = self.basis[row];
+                    self.values[basic] = bound;
+                    self.pivot(row, column);
+                }
+                None => {
+                    self.values[column] = if increase {
+                        self.upper[column].clone().unwrap()
+                    } else {
+                        self.lower[column].clone().unwrap()
+                    }
+                }
+            }
+        }
+        Status::IterationLimit
+    }
+
+    fn dual(&mut self) -> Status {
+        for _ in 0..self.iteration_limit {
+            let leaving = self
+                .basis
+                .iter()
+                .enumerate()
+                .map(|(row, &basic)| (row, self.infeasibility(basic)))
+                .filter(|(_, infeasibility)| *infeasibility != T::zero())
+                .max_by(|(_, a), (_, b)| a.abs().partial_cmp(&b.abs()).unwrap());
+            let (row, infeasibility) = match leaving {
+                Some(leaving) => leaving,
+                None => return Status::Optimal,
+            };
+            let below = infeasibility < T::zero();
+            let basic = self.basis[row];
+            let target = if below {
+                self.lower[basic].clone().unwrap()
+            } else {
+                self.upper[basic].clone().unwrap()
+            };
+
+            let reduced = self.reduced_costs();
+            let mut entering: Option<(usize, T)> = None;
+            for (column, reduced) in reduced.iter().enumerate() {
+                if self.is_basic(column) {
+                    continue;
+                }
+                let entry = &self.matrix[row][column];
+                let (negative, positive) = (*entry < -T::tolerance(), *entry > T::tolerance());
+                // The basic variable changes by `-entry` per unit increase of `column`.
+                let eligible = if below {
+                    (negative && self.can_increase(column))
+                        || (positive && self.can_decrease(column))
+                } else {
+                    (positive && self.can_increase(column))
+                        || (negative && self.can_decrease(column))
+                };
+                if !eligible {
+                    continue;
+                }
+                let ratio = (reduced.clone() / entry.clone()).abs();
+                match &entering {
+                    Some((_, best)) if *best <= ratio => {}
+                    _ => entering = Some((column, ratio)),
+                }
+            }
+            let column = match entering {
+                Some((column, _)) => column,
+                None => return Status::Infeasible,
+            };
+
+            let step =
+                (self.values[basic].clone() - target.clone()) / self.matrix[row][column].clone();
+            self.shift(column, step);
+            self.values[basic] = target;
+            self.pivot(row, column);
+        }
+        Status::IterationLimit
+    }
+
+    fn phase_one(&mut self) -> Status {
+        let original_cost = std::mem::take(&mut self.cost);
+        let first_artificial = self.columns();
+        self.cost = vec![T::zero(); first_artificial];
+
+        for row in 0..self.basis.len() {
+            let basic = self.basis[row];
+            let infeasibility = self.infeasibility(basic);
+            if infeasibility == T::zero() {
+                continue;
+            }
+            let sign = if infeasibility < T::zero() {
+                T::from_f64(-1.0)
+            } else {
+                T::from_f64(1.0)
+            };
+            for (i, entries) in self.matrix.iter_mut().enumerate() {
+                entries.push(if i == row { sign.clone() } else { T::zero() });
+            }
+            for entry in self.matrix[row].iter_mut() {
+                *entry = entry.clone() * sign.clone();
+            }
+            let artificial = self.columns();
+            self.values.push(infeasibility.abs());
+            self.lower.push(Some(T::zero()));
+            self.upper.push(None);
+            self.cost.push(T::from_f64(1.0));
+            self.values[basic] = self.values[basic].clone() - infeasibility;
+            self.basis[row] = artificial;
+        }
+
+        let status = self.primal();
+        let remaining = self.values[first_artificial..]
+            .iter()
+            .fold(T::zero(), |sum, value| sum + value.clone());
+
+        self.cost = original_cost;
+        self.cost.resize(self.columns(), T::zero());
+        for artificial in first_artificial..self.columns() {
+            self.upper[artificial] = Some(T::zero());
+        }
+
+        match status {
+            Status::Optimal if remaining > T::feasibility_tolerance() => Status::Infeasible,
+            status => status,
+        }
+    }
+}
+
+/// Restricts `range` to values at most `limit` if `upper`, else to values at least `limit`.
+fn tighten<T: Scalar>(range: &mut (Option<T>, Option<T>), limit: T, upper: bool) {
+    let bound = if upper { &mut range.1 } else { &mut range.0 };
+    match bound {
+        Some(current) if (*current <= limit) == upper => {}
+        _ => *bound = Some(limit),
+    }
+}
+
+/// Makes sure that a range of changes contains zero despite rounding errors.
+fn clamp<T: Scalar>((lower, upper): (Option<T>, Option<T>)) -> (Option<T>, Option<T>) {
+    let lower = lower.map(|lower| if lower > T::zero() { T::zero() } else { lower });
+    let upper = upper.map(|upper| if upper < T::zero() { T::zero() } else { upper });
+    (lower, upper)
+}
+
+pub(crate) fn solve(program: &MathProgram) -> LpSolution {
+    let program = LinearProgram::new(program);
+    let mut tableau: Tableau<f64> = Tableau::new(&program);
+    match tableau.solve() {
+        Status::Optimal => program.solution(
+            &tableau.values()[..program.number_of_variables()],
+            SolutionType::Optimal,
+            Some(program.dual_solution(&tableau)),
+        ),
+        Status::Infeasible => LpSolution::Infeasible,
+        Status::Unbounded => LpSolution::Unbounded,
+        Status::IterationLimit => LpSolution::Failed(String::from("Iteration limit reached")),
+    }
+}
+
+#[cfg(test)]
+mod test_super {
+    use super::*;
+    use crate::algorithm::Algorithm;
+    use crate::program::LpSolver;
+
+    #[test]
+    fn solving_lp_by_simplex_works() {
+        let mut program = MathProgram::new("lp", Sense::Maximize);
+        let x = program.add_continuous("x", Some(0.0), None);
+        let y = program.add_continuous("y", Some(0.0), None);
+        program.set_objective(3.0 * x + 2.0 * y);
+        program.add_constraint((x + y).le(4.0));
+        program.add_constraint((x + 3.0 * y).le(6.0));
+        program.add_constraint((1.0 * x).le(3.0));
+
+        let solution = LpSolver::Simplex.run(&program);
+        assert!((solution.value(x).unwrap() - 3.0).abs() < 1e-6);
+        assert!((solution.value(y).unwrap() - 1.0).abs() < 1e-6);
+        match solution {
+            LpSolution::Solved { value, .. } => assert!((value.unwrap() - 11.0).abs() < 1e-6),
+            _ => panic!("Program was not solved"),
+        }
+    }
+
+    #[test]
+    fn solving_lp_with_lower_bounds_works() {
+        let mut program = MathProgram::new("lp", Sense::Minimize);
+        let x = program.add_continuous("x", Some(0.0), None);
+        let y = program.add_continuous("y", Some(0.0), None);
+        program.set_objective(2.0 * x + 3.0 * y);
+        program.add_constraint((x + y).ge(4.0));
+        program.add_constraint((x - y).equal(2.0));
+
+        let solution = LpSolver::Simplex.run(&program);
+        assert!((solution.value(x).unwrap() - 3.0).abs() < 1e-6);
+        assert!((solution.value(y).unwrap() - 1.0).abs() < 1e-6);
+        let duals = solution.duals().unwrap();
+        assert!((duals.dual(0) - 2.5).abs() < 1e-6);
+        assert!((duals.dual(1) + 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn dual_information_is_reported() {
+        let mut program = MathProgram::new("lp", Sense::Maximize);
+        let x = program.add_continuous("x", Some(0.0), None);
+        let y = program.add_continuous("y", Some(0.0), None);
+        let z = program.add_continuous("z", Some(0.0), Some(1.0));
+        program.set_objective(3.0 * x + 2.0 * y + z);
+        program.add_constraint((x + y).le(4.0));
+        program.add_constraint((x + 3.0 * y + z).le(7.0));
+        program.add_constraint((1.0 * x).le(3.5));
+
+        let solution = LpSolver::Simplex.run(&program);
+        let duals = solution.duals().unwrap();
+        let expected = [2.0, 0.0, 1.0];
+        for (dual, expected) in duals.duals().iter().zip(expected.iter()) {
+            assert!((dual - expected).abs() < 1e-6);
+        }
+        assert!((duals.reduced_cost(z) - 1.0).abs() < 1e-6);
+        assert_eq!(0.0, duals.reduced_cost(x));
+        assert!((solution.slack(1).unwrap() - 1.0).abs() < 1e-6);
+        assert!(solution.slack(0).unwrap().abs() < 1e-6);
+
+        assert_eq!(BasisStatus::Basic, duals.variable_status(y));
+        assert_eq!(BasisStatus::AtUpper, duals.variable_status(z));
+        assert_eq!(BasisStatus::AtUpper, duals.constraint_status(0));
+        assert_eq!(BasisStatus::Basic, duals.constraint_status(1));
+
+        let close = |(lower, upper): (Option<f64>, Option<f64>), expected: (f64, Option<f64>)| {
+            (lower.unwrap() - expected.0).abs() < 1e-6
+                && match (upper, expected.1) {
+                    (Some(upper), Some(expected)) => (upper - expected).abs() < 1e-6,
+                    (upper, expected) => upper.is_none() && expected.is_none(),
+                }
+        };
+        assert!(close(duals.objective_range(x), (2.0, None)));
+        assert!(close(duals.objective_range(y), (0.0, Some(3.0))));
+        assert!(close(duals.objective_range(z), (0.0, None)));
+        assert!(close(duals.rhs_range(0), (3.5, Some(13.0 / 3.0))));
+        assert!(close(duals.rhs_range(1), (6.0, None)));
+        assert!(close(duals.rhs_range(2), (3.0, Some(4.0))));
+    }
+
+    #[test]
+    fn infeasible_lp_is_detected() {
+        let mut program = MathProgram::new("lp", Sense::Maximize);
+        let x = program.add_continuous("x", Some(0.0), None);
+        let y = program.add_continuous("y", Some(0.0), None);
+        program.set_objective(x + y);
+        program.add_constraint((x + y).le(2.0));
+        program.add_constraint((x - y).ge(3.0));
+
+        let solution = LpSolver::Simplex.run(&program);
+        assert!(matches!(solution, LpSolution::Infeasible));
+    }
+
+    #[test]
+    fn unbounded_lp_is_detected() {
+        let mut program = MathProgram::new("lp", Sense::Maximize);
+        let x = program.add_continuous("x", Some(0.0), None);
+        let y = program.add_continuous("y", Some(0.0), None);
+        program.set_objective(x + y);
+        program.add_constraint((x - y).le(2.0));
+
+        let solution = LpSolver::Simplex.run(&program);
+        assert!(matches!(solution, LpSolution::Unbounded));
+    }
+}
@@ -0,0 +1,120 @@
+use crate::algorithm::Algorithm;
+use crate::subset_sum::{Partition, Solution, SubsetSum};
+
+/// Subset sum dynamic program over a bitset of reachable sums. Adding a value shifts
+/// the bitset, so every value costs `O(target / 64)` word operations. For every sum it
+/// remembers the value that first reached it, which is enough to reconstruct a subset
+/// in `O(target)` memory.
+pub struct BitsetDP;
+
+impl BitsetDP {
+    fn solve(values: &[u32], target: u32) -> Solution {
+        let target = target as usize;
+        let words = target / 64 + 1;
+        let mut reachable = vec![0u64; words];
+        reachable[0] = 1;
+        let last_mask = u64::MAX >> (words * 64 - (target + 1));
+        let mut reached_by = vec![usize::MAX; target + 1];
+
+        for (index, &value) in values.iter().enumerate() {
+            let value = value as usize;
+            if value > target {
+                continue;
+            }
+            let (offset, shift) = (value / 64, value % 64);
+            for word in (offset..words).rev() {
+                let mut shifted = reachable[word - offset] << shift;
+                if shift > 0 && word > offset {
+                    shifted |= reachable[word - offset - 1] >> (64 - shift);
+                }
+                if word == words - 1 {
+                    shifted &= last_mask;
+                }
+                let mut fresh = shifted & !reachable[word];
+                while fresh != 0 {
+                    let bit = fresh.trailing_zeros() as usize;
+                    reached_by[word * 64 + bit] = index;
+                    fresh &= fresh - 1;
+                }
+                reachable[word] |= shifted;
+            }
+        }
+
+        let best = (0..words)
+            .rev()
+            .find(|&word| reachable[word] != 0)
+            .map(|word| word * 64 + 63 - reachable[word].leading_zeros() as usize)
+            .unwrap();
+        let mut chosen = Vec::new();
+        let mut sum = best;
+        while sum > 0 {
+            let index = reached_by[sum];
+            chosen.push(index);
+            sum -= values[index] as usize;
+        }
+        chosen.sort_unstable();
+        Solution::Solved {
+            packed_items: chosen,
+        }
+    }
+}
+
+impl Algorithm<SubsetSum<u32>> for BitsetDP {
+    fn run(&self, instance: &SubsetSum<u32>) -> Solution {
+        BitsetDP::solve(instance.values(), *instance.target())
+    }
+}
+
+impl Algorithm<Partition<u32>> for BitsetDP {
+    fn run(&self, instance: &Partition<u32>) -> Solution {
+        let total: u64 = instance
+            .values()
+            .iter()
+            .map(|&value| u64::from(value))
+            .sum();
+        BitsetDP::solve(instance.values(), (total / 2) as u32)
+    }
+}
+
+#[cfg(test)]
+mod test_super {
+    use super::*;
+    use crate::knapsack::algorithms::SimpleDP;
+    use crate::problem::SolutionKind;
+    use crate::reduction::Reduction;
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn bitset_dp_works() {
+        let instance = SubsetSum::from((vec![3, 5, 7, 9], 15));
+        let solution = BitsetDP.run(&instance);
+        assert_eq!(Some(15), solution.cost(&instance));
+
+        let instance = SubsetSum::from((vec![70, 65, 200], 60));
+        let solution = BitsetDP.run(&instance);
+        assert_eq!(Some(vec![]), solution.clone().as_solution());
+        assert_eq!(Some(0), solution.cost(&instance));
+    }
+
+    #[test]
+    fn random_validation_bitset_dp_alg() {
+        let mut rng = thread_rng();
+        let values: Vec<u32> = (0..30).map(|_| rng.gen_range(1, 150)).collect();
+        let instance = SubsetSum::from((values.clone(), rng.gen_range(0, 1500)));
+        let solution = BitsetDP.run(&instance);
+        assert_eq!(
+            instance.solve_by_reduction(&SimpleDP).cost(&instance),
+            solution.cost(&instance)
+        );
+        let chosen = solution.as_solution().unwrap();
+        let mut distinct = chosen.clone();
+        distinct.dedup();
+        assert_eq!(chosen, distinct);
+
+        let instance = Partition::from(values);
+        assert_eq!(
+            instance.solve_by_reduction(&SimpleDP).cost(&instance),
+            BitsetDP.run(&instance).cost(&instance)
+        );
+    }
+}
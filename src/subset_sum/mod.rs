@@ -0,0 +1,218 @@
+use crate::primitives::{CheckedAdd, Numeric};
+use crate::problem::{OptProblemKind, SolutionKind};
+use std::fmt::Display;
+
+pub use crate::knapsack::Solution;
+
+pub mod algorithms;
+pub mod reductions;
+
+/// Choose values with the largest sum that does not exceed the target.
+#[derive(Clone, Debug)]
+pub struct SubsetSum<T>
+where
+    T: Numeric,
+{
+    values: Vec<T>,
+    target: T,
+}
+
+impl<T> SubsetSum<T>
+where
+    T: Numeric,
+{
+    pub fn new(values: Vec<T>, target: T) -> Self {
+        if values.iter().any(|value| *value <= T::zero()) {
+            panic!("Values must be positive!");
+        }
+        SubsetSum { values, target }
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    pub fn number_of_values(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn target(&self) -> &T {
+        &self.target
+    }
+}
+
+impl<T> Display for SubsetSum<T>
+where
+    T: Numeric,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Subset sum instance\n   - target = {}\n   - values = [{}]",
+            self.target,
+            join(&self.values)
+        )
+    }
+}
+
+impl<T> OptProblemKind for SubsetSum<T>
+where
+    T: Numeric,
+{
+    type Solution = Solution;
+    type Cost = T;
+}
+
+impl<T> SolutionKind<SubsetSum<T>> for Solution
+where
+    T: Numeric,
+{
+    fn cost(&self, instance: &SubsetSum<T>) -> Option<T> {
+        match self {
+            Solution::Infeasible | Solution::Failed(_) => None,
+            Solution::Solved { packed_items } => Some(
+                packed_items
+                    .iter()
+                    .map(|&index| instance.values()[index])
+                    .sum(),
+            ),
+        }
+    }
+}
+
+/// Split the values into two parts of sums as equal as possible. A solution lists the
+/// values of the part whose sum is at most half of the total, which is its cost.
+#[derive(Clone, Debug)]
+pub struct Partition<T>
+where
+    T: Numeric,
+{
+    values: Vec<T>,
+}
+
+impl<T> Partition<T>
+where
+    T: Numeric + CheckedAdd,
+{
+    pub fn new(values: Vec<T>) -> Self {
+        if values.iter().any(|value| *value <= T::zero()) {
+            panic!("Values must be positive!");
+        }
+        let total = values
+            .iter()
+            .try_fold(T::zero(), |total, &value| total.checked_add(value));
+        if total.is_none()
+            || values
+                .iter()
+                .any(|&value| value.checked_add(value).is_none())
+        {
+            panic!("Sum of the values and twice every value must be representable!");
+        }
+        Partition { values }
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    pub fn number_of_values(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn total(&self) -> T {
+        self.values.iter().copied().sum()
+    }
+
+    /// Whether both parts of `solution` have the same sum.
+    pub fn is_perfect(&self, solution: &Solution) -> bool {
+        match solution.cost(self) {
+            Some(part) => part + part == self.total(),
+            None => false,
+        }
+    }
+}
+
+impl<T> Display for Partition<T>
+where
+    T: Numeric,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Partition instance\n   - values = [{}]",
+            join(&self.values)
+        )
+    }
+}
+
+impl<T> OptProblemKind for Partition<T>
+where
+    T: Numeric,
+{
+    type Solution = Solution;
+    type Cost = T;
+}
+
+impl<T> SolutionKind<Partition<T>> for Solution
+where
+    T: Numeric,
+{
+    fn cost(&self, instance: &Partition<T>) -> Option<T> {
+        match self {
+            Solution::Infeasible | Solution::Failed(_) => None,
+            Solution::Solved { packed_items } => Some(
+                packed_items
+                    .iter()
+                    .map(|&index| instance.values[index])
+                    .sum(),
+            ),
+        }
+    }
+}
+
+fn join<T: Display>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+impl<T> From<(Vec<T>, T)> for SubsetSum<T>
+where
+    T: Numeric,
+{
+    fn from(input: (Vec<T>, T)) -> Self {
+        SubsetSum::new(input.0, input.1)
+    }
+}
+
+impl<T> From<Vec<T>> for Partition<T>
+where
+    T: Numeric + CheckedAdd,
+{
+    fn from(values: Vec<T>) -> Self {
+        Partition::new(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cost_works() {
+        let instance = SubsetSum::from((vec![3, 5, 7], 11));
+        let solution = Solution::Solved {
+            packed_items: vec![0, 2],
+        };
+        assert_eq!(Some(10), solution.cost(&instance));
+
+        let instance = Partition::from(vec![3, 5, 7, 1]);
+        assert_eq!(16, instance.total());
+        assert!(!instance.is_perfect(&solution));
+        assert!(instance.is_perfect(&Solution::Solved {
+            packed_items: vec![1, 0]
+        }));
+    }
+}
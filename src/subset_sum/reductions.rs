@@ -0,0 +1,89 @@
+use crate::knapsack::{self, DefaultItem};
+use crate::primitives::{CheckedAdd, Numeric};
+use crate::reduction::Reduction;
+use crate::subset_sum::{Partition, Solution, SubsetSum};
+
+/// Every value becomes an item whose cost equals its weight, the target is the bag size.
+impl<T> Reduction<knapsack::Instance<DefaultItem<T>, T, T>> for SubsetSum<T>
+where
+    T: Numeric,
+{
+    fn reduce_instance(&self) -> knapsack::Instance<DefaultItem<T>, T, T> {
+        let items = self
+            .values
+            .iter()
+            .map(|&value| DefaultItem::from((value, value)))
+            .collect();
+        knapsack::Instance::new(items, self.target)
+    }
+
+    fn reduce_solution(&self, solution: &knapsack::Solution) -> Solution {
+        solution.clone()
+    }
+}
+
+/// Subset sum with half of the total as target. Values are doubled rather than the
+/// total halved, so that integral instances stay integral. [`Partition::new`] ensures
+/// that the doubled values are representable.
+impl<T> Reduction<knapsack::Instance<DefaultItem<T>, T, T>> for Partition<T>
+where
+    T: Numeric + CheckedAdd,
+{
+    fn reduce_instance(&self) -> knapsack::Instance<DefaultItem<T>, T, T> {
+        let items = self
+            .values
+            .iter()
+            .map(|&value| DefaultItem::from((value + value, value + value)))
+            .collect();
+        knapsack::Instance::new(items, self.total())
+    }
+
+    fn reduce_solution(&self, solution: &knapsack::Solution) -> Solution {
+        solution.clone()
+    }
+}
+
+#[cfg(test)]
+mod test_super {
+    use super::*;
+    use crate::knapsack::algorithms::SimpleDP;
+    use crate::knapsack::Item;
+    use crate::problem::SolutionKind;
+    use crate::program::branch_and_bound::BranchAndBound;
+
+    #[test]
+    fn subset_sum_by_reduction_works() {
+        let instance = SubsetSum::from((vec![3, 5, 7, 9], 15));
+        let solution = instance.solve_by_reduction(&SimpleDP);
+        assert_eq!(Some(15), solution.cost(&instance));
+    }
+
+    #[test]
+    fn partition_by_reduction_works() {
+        let instance = Partition::from(vec![3, 1, 1, 2, 2, 1]);
+        let solution = instance.solve_by_reduction(&SimpleDP);
+        assert_eq!(Some(5), solution.cost(&instance));
+        assert!(instance.is_perfect(&solution));
+
+        let instance = Partition::from(vec![2.0, 3.0, 4.0]);
+        let knapsack = instance.reduce_instance();
+        let solution =
+            instance.reduce_solution(&knapsack.solve_by_reduction(&BranchAndBound::default()));
+        assert_eq!(Some(4.0), solution.cost(&instance));
+        assert!(!instance.is_perfect(&solution));
+    }
+
+    #[test]
+    fn partition_reduction_handles_large_values() {
+        let instance = Partition::from(vec![1u32 << 30, 3 << 29, 5]);
+        let knapsack = instance.reduce_instance();
+        assert_eq!((5 << 29) + 5, *knapsack.bag_size());
+        assert_eq!(3 << 30, *knapsack.items()[1].weight());
+    }
+
+    #[test]
+    #[should_panic(expected = "Sum of the values and twice every value must be representable!")]
+    fn partition_rejects_values_that_overflow() {
+        Partition::new(vec![3_000_000_000u32, 1]);
+    }
+}